@@ -0,0 +1,60 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use dfsolver::puzzle::solver::{SolverMultiThreaded, SolverSingleThreaded};
+
+/// 1 January has more solutions than most dates, so the search runs long enough to compare.
+const DAY: usize = 1;
+const MONTH: usize = 1;
+
+/// Single-threaded against multi-threaded search for the whole solution set, and the search which
+/// fills the most constrained cell first.
+fn solve(c: &mut Criterion) {
+    let mut group = c.benchmark_group("find_solution_set");
+    group.sample_size(10);
+
+    group.bench_function("single_threaded", |b| {
+        b.iter(|| {
+            let mut solver = SolverSingleThreaded::new(DAY, MONTH);
+            solver.find_solution_set();
+            solver.get_solution_set().len()
+        })
+    });
+    group.bench_function("multi_threaded", |b| {
+        b.iter(|| {
+            let mut solver = SolverMultiThreaded::new(DAY, MONTH);
+            solver.find_solution_set();
+            solver.get_solution_set().len()
+        })
+    });
+    group.bench_function("most_constrained", |b| {
+        b.iter(|| {
+            SolverSingleThreaded::new(DAY, MONTH)
+                .find_solutions_most_constrained()
+                .len()
+        })
+    });
+
+    group.finish();
+}
+
+/// Counting the solutions against collecting them and removing the duplicates.
+fn count(c: &mut Criterion) {
+    let mut group = c.benchmark_group("count");
+    group.sample_size(10);
+
+    group.bench_function("count_solutions", |b| {
+        b.iter(|| SolverSingleThreaded::new(DAY, MONTH).count_solutions())
+    });
+    group.bench_function("find_solution_set_remove_duplicates", |b| {
+        b.iter(|| {
+            let mut solver = SolverSingleThreaded::new(DAY, MONTH);
+            solver.find_solution_set();
+            solver.remove_duplicates();
+            solver.get_solution_set().len()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, solve, count);
+criterion_main!(benches);
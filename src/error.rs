@@ -0,0 +1,263 @@
+use core::fmt;
+use std::error::Error;
+
+use crate::utils::array_2d::Shape;
+
+/// Errors returned by the fallible (`try_`) variants of the crate's API.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DfError {
+    /// Two arrays which must have the same shape have different shapes.
+    ShapeMismatch { expected: Shape, found: Shape },
+    /// An array was built from no rows.
+    NoRows,
+    /// A row has a different length to the rows before it.
+    RaggedRow {
+        row: usize,
+        expected_len: usize,
+        found_len: usize,
+    },
+    /// Placing the puzzle piece at the board position would put it outside of the board's bounds
+    /// or on top of another piece.
+    InvalidPlacement {
+        name: String,
+        board_position: (usize, usize),
+    },
+    /// The day and month do not make a valid date.
+    InvalidDate { day: usize, month: usize },
+    /// The board has no empty positions left to fill.
+    BoardFull,
+    /// The pieces cover a different number of cells to the number of empty cells of the board.
+    AreaMismatch {
+        board_cells: usize,
+        piece_cells: usize,
+    },
+    /// The id does not match any of the standard puzzle pieces.
+    UnknownPiece { id: u8 },
+    /// Every combination of pieces was searched without filling the board.
+    NoSolution,
+    /// A row of a piece specification comes before the first `name:` line.
+    MissingPieceName { line: usize },
+    /// A piece specification has a cell other than `#` or `.`.
+    InvalidPieceCell { line: usize, found: char },
+    /// The calendar board shows the days of the week, but no day of the week was given.
+    MissingWeekday,
+}
+
+impl fmt::Display for DfError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DfError::ShapeMismatch { expected, found } => write!(
+                f,
+                "Expected an array with shape {} x {} but found {} x {}",
+                expected.rows, expected.cols, found.rows, found.cols
+            ),
+            DfError::NoRows => write!(f, "Expected at least one row"),
+            DfError::RaggedRow {
+                row,
+                expected_len,
+                found_len,
+            } => write!(
+                f,
+                "Row {} has {} columns but expected {}",
+                row, found_len, expected_len
+            ),
+            DfError::InvalidPlacement {
+                name,
+                board_position,
+            } => write!(
+                f,
+                "{} cannot be placed at board position ({}, {})",
+                name, board_position.0, board_position.1
+            ),
+            DfError::InvalidDate { day, month } => {
+                write!(f, "Day {} of month {} is not a valid date", day, month)
+            }
+            DfError::BoardFull => write!(f, "Unable to find an empty board position"),
+            DfError::AreaMismatch {
+                board_cells,
+                piece_cells,
+            } => write!(
+                f,
+                "The pieces cover {} cells but the board has {} empty cells",
+                piece_cells, board_cells
+            ),
+            DfError::UnknownPiece { id } => write!(f, "There is no piece with id {}", id),
+            DfError::NoSolution => write!(f, "No solution was found"),
+            DfError::MissingPieceName { line } => {
+                write!(f, "Line {} has a piece row before any piece name", line)
+            }
+            DfError::InvalidPieceCell { line, found } => write!(
+                f,
+                "Line {} has the cell '{}' but expected '#' or '.'",
+                line, found
+            ),
+            DfError::MissingWeekday => {
+                write!(
+                    f,
+                    "The board shows the days of the week but no weekday was given"
+                )
+            }
+        }
+    }
+}
+
+impl Error for DfError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_shape_mismatch() {
+        // Arrange
+        let error = DfError::ShapeMismatch {
+            expected: Shape { rows: 2, cols: 3 },
+            found: Shape { rows: 3, cols: 2 },
+        };
+
+        // Act
+        let message = error.to_string();
+
+        // Assert
+        assert_eq!(
+            "Expected an array with shape 2 x 3 but found 3 x 2",
+            message
+        );
+    }
+
+    #[test]
+    fn display_no_rows() {
+        // Arrange & Act
+        let error = DfError::NoRows;
+
+        // Assert
+        assert_eq!("Expected at least one row", error.to_string());
+    }
+
+    #[test]
+    fn display_ragged_row() {
+        // Arrange
+        let error = DfError::RaggedRow {
+            row: 2,
+            expected_len: 3,
+            found_len: 4,
+        };
+
+        // Act
+        let message = error.to_string();
+
+        // Assert
+        assert_eq!("Row 2 has 4 columns but expected 3", message);
+    }
+
+    #[test]
+    fn display_invalid_placement() {
+        // Arrange
+        let error = DfError::InvalidPlacement {
+            name: "2x4 L".to_string(),
+            board_position: (6, 6),
+        };
+
+        // Act
+        let message = error.to_string();
+
+        // Assert
+        assert_eq!("2x4 L cannot be placed at board position (6, 6)", message);
+    }
+
+    #[test]
+    fn display_invalid_date() {
+        // Arrange
+        let error = DfError::InvalidDate { day: 31, month: 2 };
+
+        // Act
+        let message = error.to_string();
+
+        // Assert
+        assert_eq!("Day 31 of month 2 is not a valid date", message);
+    }
+
+    #[test]
+    fn display_board_full() {
+        // Arrange & Act
+        let error: Box<dyn Error> = Box::new(DfError::BoardFull);
+
+        // Assert
+        assert_eq!("Unable to find an empty board position", error.to_string());
+    }
+
+    #[test]
+    fn display_area_mismatch() {
+        // Arrange
+        let error = DfError::AreaMismatch {
+            board_cells: 15,
+            piece_cells: 16,
+        };
+
+        // Act
+        let message = error.to_string();
+
+        // Assert
+        assert_eq!(
+            "The pieces cover 16 cells but the board has 15 empty cells",
+            message
+        );
+    }
+
+    #[test]
+    fn display_unknown_piece() {
+        // Arrange & Act
+        let error = DfError::UnknownPiece { id: 8 };
+
+        // Assert
+        assert_eq!("There is no piece with id 8", error.to_string());
+    }
+
+    #[test]
+    fn display_no_solution() {
+        // Arrange & Act
+        let error = DfError::NoSolution;
+
+        // Assert
+        assert_eq!("No solution was found", error.to_string());
+    }
+
+    #[test]
+    fn display_missing_weekday() {
+        // Arrange & Act
+        let error = DfError::MissingWeekday;
+
+        // Assert
+        assert_eq!(
+            "The board shows the days of the week but no weekday was given",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn display_missing_piece_name() {
+        // Arrange & Act
+        let error = DfError::MissingPieceName { line: 1 };
+
+        // Assert
+        assert_eq!(
+            "Line 1 has a piece row before any piece name",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn display_invalid_piece_cell() {
+        // Arrange & Act
+        let error = DfError::InvalidPieceCell {
+            line: 3,
+            found: 'x',
+        };
+
+        // Assert
+        assert_eq!(
+            "Line 3 has the cell 'x' but expected '#' or '.'",
+            error.to_string()
+        );
+    }
+}
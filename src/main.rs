@@ -1,54 +1,207 @@
+use std::process::ExitCode;
 use std::time::SystemTime;
 
+use clap::{Parser, ValueEnum};
 use dfsolver::puzzle::{
-    piece::PieceBoardPosition,
-    solver::{SolverMultiThreaded, SolverSingleThreaded},
+    board::{self, BoardModel},
+    piece::{self, PieceBoardPosition},
+    render,
+    solver::{self, SolverSingleThreaded},
+    CalendarVariant, Weekday,
 };
 
-fn main() {
-    let day = 21;
-    let month = 5;
+/// Solves the Dragon Fjord "A-Puzzle-A-Day" calendar puzzle.
+#[derive(Parser, Debug)]
+#[command(version, about)]
+struct Args {
+    /// The day of the date to be solved.
+    #[arg(long, required_unless_present = "all")]
+    day: Option<usize>,
 
-    let start_time = SystemTime::now();
-    let mut dragon = SolverSingleThreaded::new(day, month);
-    dragon.find_solution_set();
-    let end_time = SystemTime::now();
-    let duration = end_time.duration_since(start_time).ok().unwrap();
-
-    println!(
-        "Program took {:?} to execute.",
-        (duration.as_secs() as f64 + duration.subsec_nanos() as f64 * 1e-9).to_string()
-    );
-    println!(
-        "{} solution(s) were found.",
-        dragon.get_solution_set().len()
-    );
-    dragon.remove_duplicates();
-    println!(
-        "{} unique solution(s) were found.",
-        dragon.get_solution_set().len()
-    );
-    if dragon.get_solution_set().len() > 0 {
-        print_solution(0, dragon.get_solution_set());
+    /// The month of the date to be solved.
+    #[arg(long, required_unless_present = "all")]
+    month: Option<usize>,
+
+    /// The day of the week to leave exposed. Implies the weekday-plus board, which needs it, and
+    /// cannot be used with the dragon-fjord board.
+    #[arg(long, value_enum, required_if_eq("variant", "weekday-plus"))]
+    weekday: Option<WeekdayArg>,
+
+    /// The calendar board to solve the date on. Defaults to weekday-plus if a weekday is given
+    /// and dragon-fjord otherwise.
+    #[arg(long, value_enum)]
+    variant: Option<VariantArg>,
+
+    /// Solve every date of the year and print the number of unique solutions of each.
+    #[arg(long, conflicts_with_all = ["day", "month", "weekday", "first"])]
+    all: bool,
+
+    /// Stop at the first solution.
+    #[arg(long)]
+    first: bool,
+
+    /// How the solutions are printed. Only the first solution is printed as SVG.
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    /// The solution count and building instructions for each solution.
+    Text,
+    /// Each solution drawn as a grid of piece letters.
+    Grid,
+    /// The first solution as an SVG document.
+    Svg,
+    /// Every solution as a JSON array of its pieces.
+    Json,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum VariantArg {
+    DragonFjord,
+    WeekdayPlus,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum WeekdayArg {
+    Mon,
+    Tue,
+    Wed,
+    Thu,
+    Fri,
+    Sat,
+    Sun,
+}
+
+impl From<VariantArg> for CalendarVariant {
+    fn from(variant: VariantArg) -> CalendarVariant {
+        match variant {
+            VariantArg::DragonFjord => CalendarVariant::DragonFjord,
+            VariantArg::WeekdayPlus => CalendarVariant::WeekdayPlus,
+        }
+    }
+}
+
+impl From<WeekdayArg> for Weekday {
+    fn from(weekday: WeekdayArg) -> Weekday {
+        match weekday {
+            WeekdayArg::Mon => Weekday::Mon,
+            WeekdayArg::Tue => Weekday::Tue,
+            WeekdayArg::Wed => Weekday::Wed,
+            WeekdayArg::Thu => Weekday::Thu,
+            WeekdayArg::Fri => Weekday::Fri,
+            WeekdayArg::Sat => Weekday::Sat,
+            WeekdayArg::Sun => Weekday::Sun,
+        }
     }
 }
 
-/// Print out the specified solution from the solution set
-fn print_solution(index: usize, solution_set: &Vec<Vec<PieceBoardPosition>>) {
-    println!(
-        "Solution {} of {} is shown below:",
-        index + 1,
-        solution_set.len()
-    );
-    let solution = &solution_set[index];
-    for piece in solution {
-        println!(
-            "Place {} at position (row, col) = ({}, {}) with the following orientation:\n",
-            piece.get_name(),
-            piece.get_board_position().0,
-            piece.get_board_position().1
-        );
-        println!("{}", piece.get_orienation());
-        println!("\n");
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    if args.all {
+        for ((day, month), count) in solver::solve_all_dates() {
+            println!("{:>2}/{:<2} {}", day, month, count);
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    let (day, month) = (args.day.unwrap(), args.month.unwrap());
+    if !board::is_valid_date(day, month) {
+        eprintln!("Day {} of month {} is not a valid date", day, month);
+        return ExitCode::FAILURE;
     }
+
+    let variant = match (args.variant, args.weekday) {
+        (Some(VariantArg::DragonFjord), Some(_)) => {
+            eprintln!("The dragon-fjord board has no days of the week to leave a weekday exposed");
+            return ExitCode::FAILURE;
+        }
+        (Some(variant), _) => variant.into(),
+        (None, Some(_)) => CalendarVariant::WeekdayPlus,
+        (None, None) => CalendarVariant::DragonFjord,
+    };
+    let board = match BoardModel::new_variant(day, month, variant, args.weekday.map(Weekday::from))
+    {
+        Ok(board) => board,
+        Err(error) => {
+            eprintln!("{}", error);
+            return ExitCode::FAILURE;
+        }
+    };
+    let board_layout = board.get_board_layout().clone();
+    let mut dragon = SolverSingleThreaded::with_board(board, piece::create_piece_models().to_vec());
+
+    let start_time = SystemTime::now();
+    let solutions: Vec<Vec<PieceBoardPosition>> = if args.first {
+        dragon.find_first_solution().into_iter().collect()
+    } else {
+        dragon.find_unique_solutions()
+    };
+    let duration = start_time.elapsed().unwrap_or_default();
+
+    let names: Vec<&str> = dragon
+        .get_pieces()
+        .iter()
+        .map(|piece| piece.get_name())
+        .collect();
+
+    match args.format {
+        Format::Text => {
+            println!("Program took {:?} to execute.", duration.as_secs_f64());
+            println!("{} unique solution(s) were found.", solutions.len());
+            for (index, solution) in solutions.iter().enumerate() {
+                println!("\nSolution {}:", index + 1);
+                for instruction in render::solution_to_instructions(solution) {
+                    println!("{}", instruction);
+                }
+            }
+        }
+        Format::Grid => {
+            let grids: Vec<String> = solutions
+                .iter()
+                .map(|solution| render::render_solution(&board_layout, &names, solution))
+                .collect();
+            println!("{}", grids.join("\n\n"));
+        }
+        Format::Json => println!("{}", solutions_to_json(&solutions)),
+        Format::Svg => match solutions.first() {
+            Some(solution) => println!("{}", render::solution_to_svg(variant, solution, 40)),
+            None => {
+                eprintln!("No solution was found");
+                return ExitCode::FAILURE;
+            }
+        },
+    }
+
+    ExitCode::SUCCESS
+}
+
+/// Returns the solutions as a JSON array, where each solution is an array of its pieces as
+/// `{"name", "row", "col", "orientation"}` objects and the orientation is an array of rows.
+fn solutions_to_json(solutions: &[Vec<PieceBoardPosition>]) -> serde_json::Value {
+    solutions
+        .iter()
+        .map(|solution| {
+            solution
+                .iter()
+                .map(|placement| {
+                    let (row, col) = placement.get_board_position();
+                    let orientation: Vec<Vec<u8>> = placement
+                        .get_orienation()
+                        .iter_rows()
+                        .map(<[u8]>::to_vec)
+                        .collect();
+
+                    serde_json::json!({
+                        "name": placement.get_name(),
+                        "row": row,
+                        "col": col,
+                        "orientation": orientation,
+                    })
+                })
+                .collect::<serde_json::Value>()
+        })
+        .collect()
 }
@@ -0,0 +1,155 @@
+use std::{fs::File, path::Path};
+
+use gif::{Encoder, EncodingError, Frame, Repeat};
+
+use crate::utils::array_2d::Array2D;
+
+use super::{board::BoardModel, piece::PieceBoardPosition, solver::SolverSingleThreaded};
+
+/// The global palette of the animation (RGB). The first entry is used for empty cells, the second
+/// for reserved cells (blocked cells and the date), and the remaining entries for the pieces.
+#[rustfmt::skip]
+const PALETTE: [u8; 30] = [
+    255, 255, 255,
+    60, 60, 60,
+    230, 25, 75,
+    60, 180, 75,
+    255, 225, 25,
+    0, 130, 200,
+    245, 130, 48,
+    145, 30, 180,
+    70, 240, 240,
+    240, 50, 230,
+];
+
+/// Palette index of empty cells.
+const EMPTY_INDEX: u8 = 0;
+
+/// Palette index of reserved cells.
+const RESERVED_INDEX: u8 = 1;
+
+/// Number of palette entries available for pieces.
+const PIECE_COLOURS: u8 = 8;
+
+/// Delay between frames in hundredths of a second.
+const FRAME_DELAY: u16 = 10;
+
+/// Writes an animated GIF of the search for the first solution of the specified date.
+/// Every frame shows the board after a piece is placed or removed, with each piece drawn in
+/// a distinct colour and the reserved cells drawn in dark grey.
+///
+/// # Arguments
+/// * `day` - The day of the date to be solved.
+/// * `month` - The month of the date to be solved.
+/// * `path` - The path of the GIF file to be written.
+/// * `cell_px` - The width and height of a board cell in pixels.
+pub fn write_solve_gif<P: AsRef<Path>>(
+    day: usize,
+    month: usize,
+    path: P,
+    cell_px: u16,
+) -> Result<(), EncodingError> {
+    let solver = SolverSingleThreaded::new(day, month);
+    let names: Vec<&str> = solver
+        .get_pieces()
+        .iter()
+        .map(|piece| piece.get_name())
+        .collect();
+    let reserved = BoardModel::new(day, month).get_board_layout().clone();
+
+    let width = reserved.shape().cols as u16 * cell_px;
+    let height = reserved.shape().rows as u16 * cell_px;
+
+    let mut encoder = Encoder::new(File::create(path)?, width, height, &PALETTE)?;
+    encoder.set_repeat(Repeat::Infinite)?;
+
+    for placements in solver.first_solution_frames() {
+        let pixels = render_frame(&reserved, &names, &placements, cell_px);
+        let mut frame = Frame::from_indexed_pixels(width, height, pixels, None);
+        frame.delay = FRAME_DELAY;
+        encoder.write_frame(&frame)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the palette indices of every pixel of a frame.
+///
+/// # Arguments
+/// * `reserved` - The board layout before any piece is placed.
+/// * `names` - The names of all the pieces, used to give each piece a consistent colour.
+/// * `placements` - The pieces on the board in this frame.
+/// * `cell_px` - The width and height of a board cell in pixels.
+fn render_frame(
+    reserved: &Array2D,
+    names: &[&str],
+    placements: &[PieceBoardPosition],
+    cell_px: u16,
+) -> Vec<u8> {
+    // Palette index of every board cell
+    let mut cells = reserved.clone();
+    for cell in cells.get_mut_data() {
+        *cell = if *cell == 0 {
+            EMPTY_INDEX
+        } else {
+            RESERVED_INDEX
+        };
+    }
+
+    for placement in placements {
+        let piece_index = names
+            .iter()
+            .position(|&name| name == placement.get_name())
+            .unwrap_or(0) as u8;
+        let colour = 2 + piece_index % PIECE_COLOURS;
+
+        for cell in placement.covered_cells() {
+            cells.set(cell, colour);
+        }
+    }
+
+    // Scale every cell up to a square of pixels
+    let cell_px = cell_px as usize;
+    let mut pixels: Vec<u8> = Vec::new();
+    for row in 0..cells.shape().rows {
+        for _ in 0..cell_px {
+            for col in 0..cells.shape().cols {
+                pixels.extend(std::iter::repeat_n(cells.get(row, col), cell_px));
+            }
+        }
+    }
+
+    pixels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_gif_of_first_solution() {
+        // Arrange
+        let path = std::env::temp_dir().join("dfsolver_write_gif_of_first_solution.gif");
+        let expected_frames = SolverSingleThreaded::new(6, 10)
+            .first_solution_frames()
+            .len();
+
+        // Act
+        write_solve_gif(6, 10, &path, 4).unwrap();
+
+        // Assert
+        let mut decoder = gif::DecodeOptions::new()
+            .read_info(File::open(&path).unwrap())
+            .unwrap();
+        assert_eq!((28, 28), (decoder.width(), decoder.height()));
+
+        let mut frames = 0;
+        while decoder.read_next_frame().unwrap().is_some() {
+            frames += 1;
+        }
+        assert!(frames > 1);
+        assert_eq!(expected_frames, frames);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,157 @@
+use std::fmt::Write;
+
+use super::{
+    board::{self, BoardModel},
+    piece::{self, PieceBoardPosition},
+};
+
+/// The puzzle for a date written as an exact cover problem.
+///
+/// There is a column for every empty board cell followed by a column for every piece, and a row
+/// for every valid placement of a piece. A row covers the columns of the cells the placement
+/// covers and the column of its piece. A solution is a set of rows which covers every column
+/// exactly once.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ExactCoverMatrix {
+    cells: Vec<(usize, usize)>,
+    pieces: Vec<String>,
+    placements: Vec<PieceBoardPosition>,
+    rows: Vec<Vec<usize>>,
+}
+
+impl ExactCoverMatrix {
+    /// Returns the number of columns (empty cells and pieces).
+    pub fn column_count(&self) -> usize {
+        self.cells.len() + self.pieces.len()
+    }
+
+    /// Returns the board cell of each cell column, in column order.
+    pub fn cells(&self) -> &[(usize, usize)] {
+        &self.cells
+    }
+
+    /// Returns the name of each piece column, in column order after the cell columns.
+    pub fn pieces(&self) -> &[String] {
+        &self.pieces
+    }
+
+    /// Returns the placement each row represents.
+    pub fn placements(&self) -> &[PieceBoardPosition] {
+        &self.placements
+    }
+
+    /// Returns the (0 based) columns covered by each row, in ascending order.
+    pub fn rows(&self) -> &[Vec<usize>] {
+        &self.rows
+    }
+
+    /// Returns the matrix as sparse text in a DIMACS like format. The first line is
+    /// `p <columns> <rows>`, and every following line is a row listing the (1 based) columns
+    /// it covers, terminated by `0`.
+    pub fn to_sparse_text(&self) -> String {
+        let mut text = format!("p {} {}\n", self.column_count(), self.rows.len());
+
+        for row in &self.rows {
+            for column in row {
+                write!(text, "{} ", column + 1).unwrap();
+            }
+            text.push_str("0\n");
+        }
+
+        text
+    }
+}
+
+/// Returns the exact cover matrix of the puzzle for the specified date, using the standard set
+/// of puzzle pieces.
+///
+/// # Arguments
+/// * `day` - The day of the date.
+/// * `month` - The month of the date.
+pub fn export_exact_cover(day: usize, month: usize) -> ExactCoverMatrix {
+    let board = BoardModel::new(day, month);
+    let pieces = piece::create_piece_models();
+    let cells = board::get_all_empty_positions(board.get_board_layout());
+    let min_area = piece::min_piece_area(&pieces);
+
+    let mut placements: Vec<PieceBoardPosition> = Vec::new();
+    let mut rows: Vec<Vec<usize>> = Vec::new();
+    for (piece_index, piece) in pieces.iter().enumerate() {
+        for placement in board.valid_placements(piece, min_area) {
+            let mut row: Vec<usize> = placement
+                .covered_cells()
+                .iter()
+                .map(|cell| {
+                    cells
+                        .iter()
+                        .position(|empty_cell| empty_cell == cell)
+                        .unwrap()
+                })
+                .collect();
+            row.sort();
+            row.push(cells.len() + piece_index);
+
+            rows.push(row);
+            placements.push(placement);
+        }
+    }
+
+    ExactCoverMatrix {
+        cells,
+        pieces: pieces
+            .iter()
+            .map(|piece| piece.get_name().to_string())
+            .collect(),
+        placements,
+        rows,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exact_cover_columns_and_rows() {
+        // Arrange
+        let pieces = piece::create_piece_models();
+
+        // Act
+        let matrix = export_exact_cover(6, 10);
+
+        // Assert
+        // 41 empty cells and 8 pieces
+        assert_eq!(41 + 8, matrix.column_count());
+        assert!(!matrix.rows().is_empty());
+        for (row, placement) in matrix.rows().iter().zip(matrix.placements()) {
+            let piece_index = pieces
+                .iter()
+                .position(|piece| piece.get_name() == placement.get_name())
+                .unwrap();
+            let piece_cells = pieces[piece_index]
+                .current_orientation()
+                .data()
+                .iter()
+                .filter(|&&cell| cell == 1)
+                .count();
+
+            assert_eq!(piece_cells + 1, row.len());
+            assert_eq!(Some(&(41 + piece_index)), row.last());
+        }
+    }
+
+    #[test]
+    fn exact_cover_sparse_text() {
+        // Arrange
+        let matrix = export_exact_cover(6, 10);
+
+        // Act
+        let text = matrix.to_sparse_text();
+
+        // Assert
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(format!("p 49 {}", matrix.rows().len()), lines[0]);
+        assert_eq!(matrix.rows().len() + 1, lines.len());
+        assert!(lines[1..].iter().all(|line| line.ends_with(" 0")));
+    }
+}
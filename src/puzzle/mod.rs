@@ -1,3 +1,10 @@
+#[cfg(feature = "gif")]
+pub mod animation;
 pub mod board;
+pub mod exact_cover;
 pub mod piece;
+pub mod render;
 pub mod solver;
+
+pub use board::{date_to_cells, CalendarVariant, Weekday};
+pub use exact_cover::{export_exact_cover, ExactCoverMatrix};
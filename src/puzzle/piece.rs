@@ -1,16 +1,18 @@
 use core::fmt;
-use std::usize;
 
 use crate::array2D;
+use crate::error::DfError;
 
 use super::super::utils::array_2d::{Array2D, Axes};
 
 /// A valid orientation and board position of a puzzle piece.
 #[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PieceBoardPosition {
     name: String,
     board_position: (usize, usize),
     orientation: Array2D,
+    flipped: bool,
 }
 
 impl PieceBoardPosition {
@@ -25,17 +27,52 @@ impl PieceBoardPosition {
     pub fn get_orienation(&self) -> Array2D {
         self.orientation.clone()
     }
+
+    /// Returns true if the orientation is one of the flipped orientations of the piece, i.e. the
+    /// back of the piece is facing up.
+    pub fn is_flipped(&self) -> bool {
+        self.flipped
+    }
+
+    /// Returns the board positions of the cells covered by the piece.
+    pub fn covered_cells(&self) -> Vec<(usize, usize)> {
+        let (row, col) = self.board_position;
+        let mut cells: Vec<(usize, usize)> = Vec::new();
+
+        for row_piece in 0..self.orientation.shape().rows {
+            for col_piece in 0..self.orientation.shape().cols {
+                if self.orientation.get(row_piece, col_piece) == 1 {
+                    cells.push((row + row_piece, col + col_piece));
+                }
+            }
+        }
+
+        cells
+    }
+}
+
+/// The search state of a puzzle piece, i.e. everything about a `PieceModel` which changes while
+/// it is placed and moved through its orientations.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PieceState {
+    orientation_index: usize,
+    translation_count: usize,
+    orientation_exhausted: bool,
+    is_used: bool,
+    board_position: Option<(usize, usize)>,
 }
 
 /// Model of a puzzle piece that can be placed on board.
 /// Contains information required to determine all unique
 /// piece orientations.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PieceModel {
     // Arguments
     name: String,
     initial_orientation: Array2D,
-    current_orientation: Array2D,
+    orientations: Vec<Array2D>,
     board_position: Option<(usize, usize)>,
 
     // Restrictions
@@ -43,9 +80,8 @@ pub struct PieceModel {
     is_flippable: bool,
 
     // Flags
-    rotation_count: usize,
+    orientation_index: usize,
     translation_count: usize,
-    has_flipped: bool,
     orientation_exhausted: bool,
     is_used: bool,
 }
@@ -58,30 +94,146 @@ impl PieceModel {
     /// `initial_orientation` - The initial orientation of the puzzle piece, stored in a `Array2D` struct.
     /// `max_rotations` - The number of rotations which provide a unique puzzle piece (takes into account symmetry).
     /// `is_flippable` - Indicates if the puzzle piece has symmetry or asymmerty, which means the piece should be flipped.
+    ///
+    /// Rows and columns of zeros around the edges of `initial_orientation` are trimmed, so the
+    /// piece is anchored to the top left of its orientation however it was padded.
     pub fn new(
         name: String,
         initial_orientation: Array2D,
         max_rotations: usize,
         is_flippable: bool,
     ) -> PieceModel {
+        let initial_orientation = initial_orientation.trimmed();
         PieceModel {
             name,
-            current_orientation: initial_orientation.clone(),
+            orientations: PieceModel::build_orientations(&initial_orientation, max_rotations),
             initial_orientation,
             board_position: None,
             max_rotations,
             is_flippable,
-            rotation_count: 0,
+            orientation_index: 0,
             translation_count: 0,
-            has_flipped: false,
             orientation_exhausted: false,
             is_used: false,
         }
     }
 
+    /// Returns a new instance of `PieceModel`, working out the number of unique rotations and if
+    /// the piece should be flipped from its shape. The rotations of the initial orientation are
+    /// compared to find when they repeat, and the piece is flippable if its mirror image is not
+    /// one of the rotations.
+    ///
+    /// # Arguments
+    /// `name`  - The name of the puzzle piece (used only for indentification purposes).
+    /// `initial_orientation` - The initial orientation of the puzzle piece, stored in a `Array2D` struct.
+    pub fn from_shape(name: String, initial_orientation: Array2D) -> PieceModel {
+        let initial_orientation = initial_orientation.trimmed();
+
+        let mut rotations: Vec<Array2D> = vec![initial_orientation.clone()];
+        let mut rotation = initial_orientation.clone();
+        loop {
+            rotation.rotate90(1);
+            if rotation == initial_orientation {
+                break;
+            }
+            rotations.push(rotation.clone());
+        }
+
+        let mut mirror = initial_orientation.clone();
+        mirror.flip(Axes::Y);
+        let is_flippable = !rotations.contains(&mirror);
+
+        PieceModel::new(name, initial_orientation, rotations.len() - 1, is_flippable)
+    }
+
+    /// Returns the rotations of the initial orientation followed by the rotations of the flipped
+    /// orientation, in the order the piece moves through them. The flipped orientations are built
+    /// even if the piece is not flippable so that `set_flippable` can change its mind later.
+    fn build_orientations(initial_orientation: &Array2D, max_rotations: usize) -> Vec<Array2D> {
+        let mut orientation = initial_orientation.clone();
+        let mut orientations: Vec<Array2D> = vec![orientation.clone()];
+
+        for index in 1..2 * (max_rotations + 1) {
+            if index == max_rotations + 1 {
+                orientation.flip(Axes::Y);
+            } else {
+                orientation.rotate90(1);
+            }
+            orientations.push(orientation.clone());
+        }
+
+        orientations
+    }
+
+    /// Returns the puzzle pieces described by a piece specification. Each piece starts with a
+    /// `name:` line followed by a row per line of its grid, where `#` is a cell covered by the
+    /// piece and `.` is an empty cell. Blank lines are ignored. The orientations of each piece
+    /// are worked out from its shape as with `from_shape`.
+    ///
+    /// # Arguments
+    /// `input` - The piece specification.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dfsolver::puzzle::piece::PieceModel;
+    /// let pieces = PieceModel::from_spec_str("name: L\n#..\n#..\n###").unwrap();
+    /// assert_eq!("L", pieces[0].get_name());
+    /// assert_eq!(5, pieces[0].area());
+    /// assert_eq!(4, pieces[0].unique_orientations().len());
+    /// ```
+    pub fn from_spec_str(input: &str) -> Result<Vec<PieceModel>, DfError> {
+        let mut specs: Vec<(String, Vec<Vec<u8>>)> = Vec::new();
+
+        for (index, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix("name:") {
+                specs.push((name.trim().to_string(), Vec::new()));
+                continue;
+            }
+
+            let row = line
+                .chars()
+                .map(|cell| match cell {
+                    '#' => Ok(1),
+                    '.' => Ok(0),
+                    found => Err(DfError::InvalidPieceCell {
+                        line: index + 1,
+                        found,
+                    }),
+                })
+                .collect::<Result<Vec<u8>, DfError>>()?;
+
+            match specs.last_mut() {
+                Some((_, rows)) => rows.push(row),
+                None => return Err(DfError::MissingPieceName { line: index + 1 }),
+            }
+        }
+
+        specs
+            .into_iter()
+            .map(|(name, rows)| Ok(PieceModel::from_shape(name, Array2D::from_rows(&rows)?)))
+            .collect()
+    }
+
+    /// Returns the piece specification of the puzzle piece, in the format read by
+    /// `from_spec_str`.
+    pub fn to_spec_string(&self) -> String {
+        let mut spec = format!("name: {}\n", self.name);
+        for row in self.initial_orientation.iter_rows() {
+            spec.extend(row.iter().map(|&cell| if cell == 1 { '#' } else { '.' }));
+            spec.push('\n');
+        }
+
+        spec
+    }
+
     /// Returns an immutable reference to the current piece orientation
     pub fn current_orientation(&self) -> &Array2D {
-        &self.current_orientation
+        &self.orientations[self.orientation_index]
     }
 
     /// Returns a copy of the current pieces translation count
@@ -94,43 +246,74 @@ impl PieceModel {
         self.name.as_str()
     }
 
-    /// Rotates the puzzle piece model it is called on by 90 degrees anti-clockwise.
-    fn rotate(&mut self) {
-        self.current_orientation.rotate90(1);
-        self.rotation_count += 1;
+    /// Returns the number of board cells the piece covers.
+    pub fn area(&self) -> usize {
+        self.initial_orientation
+            .data()
+            .iter()
+            .filter(|&&cell| cell == 1)
+            .count()
+    }
+
+    /// Returns the number of orientations the piece moves through, which is all of the
+    /// precomputed orientations if the piece is flippable and only the unflipped ones if not.
+    fn orientation_count(&self) -> usize {
+        if self.is_flippable {
+            2 * (self.max_rotations + 1)
+        } else {
+            self.max_rotations + 1
+        }
+    }
+
+    /// Returns the number of quarter turns of the current orientation, counted from the flipped
+    /// orientation if the piece has been flipped.
+    #[cfg(test)]
+    fn rotation_count(&self) -> usize {
+        self.orientation_index % (self.max_rotations + 1)
     }
 
-    /// Flips the puzzle piece model it is called on along vertical axis (i.e. horizontally flips piece)
-    fn flip(&mut self) {
-        self.current_orientation.flip(Axes::Y);
-        self.has_flipped = true;
-        self.rotation_count = 0;
+    /// Returns true if the current orientation is one of the flipped orientations.
+    fn has_flipped(&self) -> bool {
+        self.orientation_index > self.max_rotations
+    }
+
+    /// Returns a snapshot of the search state of the piece, which can be restored with `restore`.
+    pub fn snapshot(&self) -> PieceState {
+        PieceState {
+            orientation_index: self.orientation_index,
+            translation_count: self.translation_count,
+            orientation_exhausted: self.orientation_exhausted,
+            is_used: self.is_used,
+            board_position: self.board_position,
+        }
+    }
+
+    /// Returns the piece to the search state of a snapshot taken with `snapshot`.
+    ///
+    /// # Arguments
+    /// `state` - The snapshot of the piece to restore.
+    pub fn restore(&mut self, state: PieceState) {
+        self.orientation_index = state.orientation_index;
+        self.translation_count = state.translation_count;
+        self.orientation_exhausted = state.orientation_exhausted;
+        self.is_used = state.is_used;
+        self.board_position = state.board_position;
     }
 
     /// Resets the puzzle piece model back to its initial condition.
     pub fn reset(&mut self) {
-        self.current_orientation = self.initial_orientation.clone();
-        self.rotation_count = 0;
+        self.orientation_index = 0;
         self.translation_count = 0;
-        self.has_flipped = false;
         self.orientation_exhausted = false;
     }
 
     /// Change the orientation of the puzzle piece model it is called on to return a unique new orientation.
     fn change_orientation(&mut self) {
-        // Check if max number of rotations has been made
-        if self.rotation_count == self.max_rotations {
-            // Check if piece is flippable or has already been flipped
-            if self.is_flippable & !self.has_flipped {
-                // Shape if flippable and has not been flipped
-                self.flip()
-            } else {
-                // Shape is either not flippable or has already been flipped
-                self.orientation_exhausted = true;
-            }
+        if self.orientation_index + 1 < self.orientation_count() {
+            self.orientation_index += 1;
         } else {
-            // Max number of rotations hasn't been reached yet
-            self.rotate();
+            // Every rotation, and every flipped rotation if the piece is flippable, has been used
+            self.orientation_exhausted = true;
         }
     }
 
@@ -140,18 +323,14 @@ impl PieceModel {
     /// If there are zeros in the top row of the piece, the piece will be
     /// incrementally translated until a value of 1 is at the board position.
     fn translate(&mut self) {
-
         // Loop while piece can still be translated
         while !self.is_translation_exhausted() {
             self.translation_count += 1;
         }
-        
     }
 
     fn is_translation_exhausted(&self) -> bool {
-        self.current_orientation
-            .get(0, self.translation_count as usize)
-            == 1
+        self.current_orientation().get(0, self.translation_count) == 1
     }
 
     /// Translates and or rotates the puzzle piece model it is called on
@@ -176,12 +355,27 @@ impl PieceModel {
         self.is_used = is_used;
     }
 
+    /// Sets whether the puzzle piece may be flipped over when searching for its orientations.
+    /// Should be set before the orientations of the piece are searched.
+    ///
+    /// # Arguments
+    /// `is_flippable` - A boolean indicating if the piece may be flipped.
+    pub fn set_flippable(&mut self, is_flippable: bool) {
+        self.is_flippable = is_flippable;
+    }
+
+    /// Returns true if the piece may be flipped over when searching for its orientations.
+    pub fn is_flippable(&self) -> bool {
+        self.is_flippable
+    }
+
     /// Returns a immutable reference to the `is_used` field
     pub fn is_used(&self) -> &bool {
         &self.is_used
     }
 
-    /// Returns an immutable reference to the `is_exhausted` field
+    /// Returns an immutable reference to the `is_exhausted` field, which is only set once every
+    /// translation of the last orientation has been moved through by `next_unique_orientation`.
     pub fn is_exhausted(&self) -> &bool {
         &self.orientation_exhausted
     }
@@ -206,108 +400,170 @@ impl PieceModel {
         &self.board_position
     }
 
-    /// Returns a `PiecePosition` object from the piece model is it called on.
+    /// Returns a copy of the piece's board position.
+    pub fn board_position(&self) -> Option<(usize, usize)> {
+        self.board_position
+    }
+
+    /// Returns the index of the current orientation in `unique_orientations`.
+    pub fn current_orientation_index(&self) -> usize {
+        self.orientation_index
+    }
+
+    /// Returns every unique orientation of the piece, in the order they are reached by
+    /// `next_unique_orientation`. The rotations of the initial orientation come first, followed
+    /// by the rotations of the flipped orientation if the piece is flippable. The orientations
+    /// are worked out once when the piece is built.
+    pub fn unique_orientations(&self) -> Vec<Array2D> {
+        self.orientations[..self.orientation_count()].to_vec()
+    }
+
+    /// Resets the piece and drives `next_unique_orientation` until the orientations are
+    /// exhausted, returning each orientation it moves to. Translations of an orientation only
+    /// move its board position, so the orientation is collected once when it is first reached.
+    #[cfg(test)]
+    fn collect_state_machine_orientations(&mut self) -> Vec<Array2D> {
+        self.reset();
+
+        let mut orientations: Vec<Array2D> = Vec::new();
+        while !self.orientation_exhausted {
+            if self.translation_count == 0 {
+                orientations.push(self.current_orientation().clone());
+            }
+            self.next_unique_orientation();
+        }
+
+        orientations
+    }
+
+    /// Returns a `PieceBoardPosition` object from the piece model is it called on.
+    ///
+    /// # Panics!
+    /// If the piece has no board position.
     pub fn get_piece_board_position(&self) -> PieceBoardPosition {
         PieceBoardPosition {
             name: self.name.clone(),
             board_position: self.board_position.unwrap(),
-            orientation: self.current_orientation.clone(),
+            orientation: self.current_orientation().clone(),
+            flipped: self.has_flipped(),
+        }
+    }
+
+    /// Returns the placement of the piece mirrored from left to right across a board, as the
+    /// same piece would be placed in the mirror image of the board. The placement is flipped if
+    /// its mirrored orientation is only reached by flipping the piece over.
+    ///
+    /// # Arguments
+    /// `placement` - A placement of the piece.
+    /// `board_cols` - The number of columns of the board the piece is placed on.
+    pub fn mirrored_placement(
+        &self,
+        placement: &PieceBoardPosition,
+        board_cols: usize,
+    ) -> PieceBoardPosition {
+        let mut orientation = placement.orientation.clone();
+        orientation.flip(Axes::Y);
+        let (row, col) = placement.board_position;
+        let orientation_index = self
+            .orientations
+            .iter()
+            .position(|other| *other == orientation)
+            .unwrap();
+
+        PieceBoardPosition {
+            name: placement.name.clone(),
+            board_position: (row, board_cols - col - orientation.shape().cols),
+            flipped: orientation_index > self.max_rotations,
+            orientation,
         }
     }
 }
 
 impl fmt::Display for PieceModel {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Piece: {}\n", self.get_name())?;
+        writeln!(f, "Piece: {}", self.get_name())?;
         match self.get_board_position() {
             Some(positoin) => {
-                write!(f, "Board Positon: ({}, {})\n", positoin.0, positoin.1)?;
+                writeln!(f, "Board Positon: ({}, {})", positoin.0, positoin.1)?;
             }
-            None => write!(f, "Board Position: (_, _)\n")?,
+            None => writeln!(f, "Board Position: (_, _)")?,
         }
 
-        write!(f, "Current orientation:\n")?;
+        writeln!(f, "Current orientation:")?;
 
-        return write!(f, "{}", self.current_orientation);
+        write!(f, "{}", self.current_orientation())
     }
 }
 
+/// Returns the number of board cells covered by the smallest of the pieces, or 0 if there are
+/// no pieces. An empty region of the board with fewer cells cannot be filled by the pieces.
+///
+/// # Arguments
+/// * `pieces` - The puzzle pieces to be compared.
+pub fn min_piece_area(pieces: &[PieceModel]) -> usize {
+    pieces.iter().map(|piece| piece.area()).min().unwrap_or(0)
+}
+
 #[rustfmt::skip::macros(array2D)]
 pub fn create_piece_models() -> [PieceModel; 8] {
     [
-        PieceModel::new(
+        PieceModel::from_shape(
             "2x3 No Hole".to_string(),
             array2D!(
                 [1, 1, 1],
                 [1, 1, 1]
             ),
-            1,
-            false,
         ),
-        PieceModel::new(
+        PieceModel::from_shape(
             "2x3 Middle Hole".to_string(),
             array2D!(
                 [1, 0, 1],
                 [1, 1, 1]
             ),
-            3,
-            false,
         ),
-        PieceModel::new(
+        PieceModel::from_shape(
             "2x3 End Hole".to_string(),
             array2D!(
                 [1, 1, 0],
                 [1, 1, 1]
             ),
-            3,
-            true,
         ),
-        PieceModel::new(
+        PieceModel::from_shape(
             "2x4 Zig Zag".to_string(),
             array2D!(
                 [0, 0, 1, 1],
                 [1, 1, 1, 0]
             ),
-            3,
-            true,
         ),
-        PieceModel::new(
+        PieceModel::from_shape(
             "2x4 Tee.".to_string(),
             array2D!(
                 [0, 0, 1, 0],
                 [1, 1, 1, 1]
             ),
-            3,
-            true,
         ),
-        PieceModel::new(
+        PieceModel::from_shape(
             "2x4 L".to_string(),
             array2D!(
                 [0, 0, 0, 1],
                 [1, 1, 1, 1]
             ),
-            3,
-            true,
         ),
-        PieceModel::new(
+        PieceModel::from_shape(
             "3x3 Zig Zag".to_string(),
             array2D!(
                 [1, 0, 0],
                 [1, 1, 1],
                 [0, 0, 1]
             ),
-            1,
-            true,
         ),
-        PieceModel::new(
+        PieceModel::from_shape(
             "3x3 L".to_string(),
             array2D!(
                 [1, 0, 0],
                 [1, 0, 0],
                 [1, 1, 1]
             ),
-            3,
-            false,
         ),
     ]
 }
@@ -316,6 +572,309 @@ pub fn create_piece_models() -> [PieceModel; 8] {
 mod tests {
     use super::*;
     use crate::array2D;
+    use crate::utils::array_2d::Shape;
+
+    #[test]
+    fn from_shape_no_hole_has_two_orientations() {
+        // Arrange & Act
+        let piece =
+            PieceModel::from_shape("2x3 No Hole".to_string(), array2D!([1, 1, 1], [1, 1, 1]));
+
+        // Assert
+        assert_eq!(1, piece.max_rotations);
+        assert!(!piece.is_flippable);
+        assert_eq!(2, piece.unique_orientations().len());
+    }
+
+    #[test]
+    fn from_shape_l_has_eight_orientations() {
+        // Arrange & Act
+        let piece =
+            PieceModel::from_shape("2x4 L".to_string(), array2D!([0, 0, 0, 1], [1, 1, 1, 1]));
+
+        // Assert
+        assert_eq!(3, piece.max_rotations);
+        assert!(piece.is_flippable);
+        assert_eq!(8, piece.unique_orientations().len());
+    }
+
+    #[test]
+    fn standard_pieces_have_distinct_orientations() {
+        // Arrange
+        let expected_counts = [2, 4, 8, 8, 8, 8, 4, 4];
+
+        for (piece, expected_count) in create_piece_models().iter().zip(expected_counts) {
+            // Act
+            let orientations = piece.unique_orientations();
+
+            // Assert
+            assert_eq!(expected_count, orientations.len(), "{}", piece.get_name());
+            for (index, orientation) in orientations.iter().enumerate() {
+                assert!(
+                    !orientations[index + 1..].contains(orientation),
+                    "{} repeats an orientation",
+                    piece.get_name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn spec_round_trip_of_standard_pieces() {
+        // Arrange
+        let pieces = create_piece_models();
+        let spec: Vec<String> = pieces.iter().map(|piece| piece.to_spec_string()).collect();
+
+        // Act
+        let parsed = PieceModel::from_spec_str(&spec.join("\n")).unwrap();
+
+        // Assert
+        assert_eq!(pieces.len(), parsed.len());
+        for (piece, parsed_piece) in pieces.iter().zip(&parsed) {
+            assert_eq!(piece.get_name(), parsed_piece.get_name());
+            assert_eq!(
+                piece.unique_orientations(),
+                parsed_piece.unique_orientations()
+            );
+        }
+    }
+
+    #[test]
+    fn spec_rejects_unknown_cell() {
+        // Arrange
+        let spec = "name: Bar\n###\n\nname: Dot\n#x";
+
+        // Act
+        let result = PieceModel::from_spec_str(spec);
+
+        // Assert
+        assert_eq!(
+            Some(DfError::InvalidPieceCell {
+                line: 5,
+                found: 'x'
+            }),
+            result.err()
+        );
+    }
+
+    #[test]
+    fn spec_rejects_rows_without_name() {
+        // Arrange & Act
+        let result = PieceModel::from_spec_str("\n##\nname: Bar\n##");
+
+        // Assert
+        assert_eq!(Some(DfError::MissingPieceName { line: 2 }), result.err());
+    }
+
+    #[test]
+    fn spec_rejects_piece_without_rows() {
+        // Arrange & Act
+        let result = PieceModel::from_spec_str("name: Empty\nname: Bar\n##");
+
+        // Assert
+        assert_eq!(Some(DfError::NoRows), result.err());
+    }
+
+    #[test]
+    fn area_of_2x3_pieces() {
+        // Arrange
+        let pieces = create_piece_models();
+
+        // Act
+        let areas: Vec<usize> = pieces[..3].iter().map(|piece| piece.area()).collect();
+
+        // Assert
+        assert_eq!(vec![6, 5, 5], areas);
+    }
+
+    #[test]
+    fn min_piece_area_of_standard_pieces() {
+        // Arrange
+        let pieces = create_piece_models();
+
+        // Act & Assert
+        assert_eq!(5, min_piece_area(&pieces));
+        assert_eq!(6, min_piece_area(&pieces[..1]));
+        assert_eq!(0, min_piece_area(&[]));
+    }
+
+    #[test]
+    fn unique_orientations_are_rotations_then_flipped_rotations() {
+        // Arrange
+        let piece = create_piece_models()[5].clone();
+        let mut orientation = piece.current_orientation().clone();
+        let mut expected_result = vec![orientation.clone()];
+        for _ in 0..3 {
+            orientation.rotate90(1);
+            expected_result.push(orientation.clone());
+        }
+        orientation.flip(Axes::Y);
+        for _ in 0..4 {
+            expected_result.push(orientation.clone());
+            orientation.rotate90(1);
+        }
+
+        // Act
+        let orientations = piece.unique_orientations();
+
+        // Assert
+        assert_eq!(expected_result, orientations);
+    }
+
+    #[test]
+    fn used_flag_and_board_position_accessors() {
+        // Arrange
+        let mut piece = create_piece_models()[5].clone();
+
+        // Act
+        piece.set_used(true);
+        piece.next_unique_orientation();
+        piece.set_board_position(Some((2, 4)));
+
+        // Assert
+        assert!(*piece.is_used());
+        assert_eq!(&Some((2, 1)), piece.get_board_position());
+        let placement = piece.get_piece_board_position();
+        assert_eq!("2x4 L", placement.get_name());
+        assert_eq!((2, 1), placement.get_board_position());
+        assert_eq!(piece.current_orientation(), &placement.get_orienation());
+
+        piece.set_used(false);
+        piece.set_board_position(None);
+        assert!(!*piece.is_used());
+        assert_eq!(None, piece.board_position());
+    }
+
+    #[test]
+    fn is_exhausted_after_last_translation() {
+        // Arrange
+        let mut piece = create_piece_models()[5].clone();
+        let last_orientation = piece.unique_orientations().len() - 1;
+
+        // Act
+        let mut steps = 0;
+        while !piece.is_exhausted() {
+            assert!(steps < 100, "the orientations of the piece never ran out");
+            let (index, translation) = (
+                piece.current_orientation_index(),
+                piece.get_translation_count(),
+            );
+            piece.next_unique_orientation();
+            steps += 1;
+
+            // Assert
+            if *piece.is_exhausted() {
+                assert_eq!(last_orientation, index);
+                assert_eq!(1, piece.current_orientation().get(0, translation));
+            }
+        }
+        assert!(steps > last_orientation + 1);
+    }
+
+    #[test]
+    fn snapshot_restores_advanced_piece() {
+        // Arrange
+        let mut piece = create_piece_models()[5].clone();
+        for _ in 0..6 {
+            piece.next_unique_orientation();
+        }
+        piece.set_board_position(Some((2, 4)));
+        let expected_orientation = piece.current_orientation().clone();
+        let expected_position = piece.board_position();
+        let state = piece.snapshot();
+
+        // Act
+        while !piece.is_exhausted() {
+            piece.next_unique_orientation();
+        }
+        piece.set_used(true);
+        piece.restore(state.clone());
+
+        // Assert
+        assert_eq!(state, piece.snapshot());
+        assert_eq!(&expected_orientation, piece.current_orientation());
+        assert_eq!(expected_position, piece.board_position());
+        assert!(!piece.is_used());
+        assert!(!piece.is_exhausted());
+    }
+
+    #[test]
+    fn mirrored_placement_of_l_is_flipped() {
+        // Arrange
+        let mut piece = create_piece_models()[5].clone();
+        piece.set_board_position(Some((1, 2)));
+        let placement = piece.get_piece_board_position();
+
+        // Act
+        let mirrored = piece.mirrored_placement(&placement, 7);
+
+        // Assert
+        assert_eq!((1, 1), mirrored.get_board_position());
+        assert_eq!(
+            array2D!([1, 0, 0, 0], [1, 1, 1, 1]),
+            mirrored.get_orienation()
+        );
+        assert!(mirrored.is_flipped());
+        assert_eq!(placement, piece.mirrored_placement(&mirrored, 7));
+    }
+
+    #[test]
+    fn quarter_turns_of_standard_pieces_keep_cells_and_swap_shape() {
+        for piece in create_piece_models() {
+            // Arrange
+            let initial = piece.current_orientation().clone();
+            let cells = initial.data().iter().filter(|&&cell| cell == 1).count();
+            let mut orientation = initial.clone();
+
+            for turn in 1..=4 {
+                // Act
+                orientation.rotate90(1);
+
+                // Assert
+                assert_eq!(
+                    cells,
+                    orientation.data().iter().filter(|&&cell| cell == 1).count(),
+                    "{} after {} quarter turn(s)",
+                    piece.get_name(),
+                    turn
+                );
+
+                let expected_shape = if turn % 2 == 1 {
+                    Shape {
+                        rows: initial.shape().cols,
+                        cols: initial.shape().rows,
+                    }
+                } else {
+                    initial.shape().clone()
+                };
+                assert_eq!(
+                    expected_shape,
+                    *orientation.shape(),
+                    "{} after {} quarter turn(s)",
+                    piece.get_name(),
+                    turn
+                );
+            }
+            assert_eq!(initial, orientation);
+        }
+    }
+
+    #[test]
+    fn rotate_2x4_l_once_is_4x2() {
+        // Arrange
+        let mut piece = create_piece_models()[5].clone();
+
+        // Act
+        piece.change_orientation();
+
+        // Assert
+        assert_eq!("2x4 L", piece.get_name());
+        assert_eq!(1, piece.rotation_count());
+        assert_eq!(
+            &Shape { rows: 4, cols: 2 },
+            piece.current_orientation().shape()
+        );
+    }
 
     #[test]
     fn change_piece_orientation_once() {
@@ -331,8 +890,8 @@ mod tests {
         piece.change_orientation();
 
         // Assert
-        assert_eq!(piece.rotation_count, 1);
-        assert_eq!(piece.has_flipped, false);
+        assert_eq!(piece.rotation_count(), 1);
+        assert!(!piece.has_flipped());
     }
 
     #[test]
@@ -353,8 +912,8 @@ mod tests {
         }
 
         // Assert
-        assert_eq!(piece.rotation_count, 3);
-        assert_eq!(piece.has_flipped, false);
+        assert_eq!(piece.rotation_count(), 3);
+        assert!(!piece.has_flipped());
     }
 
     #[test]
@@ -375,8 +934,114 @@ mod tests {
         }
 
         // Assert
-        assert_eq!(piece.rotation_count, 0);
-        assert_eq!(piece.has_flipped, true);
+        assert_eq!(piece.rotation_count(), 0);
+        assert!(piece.has_flipped());
+    }
+
+    #[test]
+    fn current_orientation_index_matches_unique_orientations() {
+        // Arrange
+        let mut piece = PieceModel::new(
+            "2x3 End Hole".to_string(),
+            array2D!([1, 1, 0], [1, 1, 1]),
+            3,
+            true,
+        );
+        let orientations = piece.unique_orientations();
+
+        for expected_index in 0..orientations.len() {
+            // Act
+            let index = piece.current_orientation_index();
+
+            // Assert
+            assert_eq!(expected_index, index);
+            assert_eq!(&orientations[index], piece.current_orientation());
+            piece.change_orientation();
+        }
+
+        assert_eq!(8, orientations.len());
+        assert!(piece.is_exhausted());
+    }
+
+    #[test]
+    fn set_flippable_false_skips_flipped_orientations() {
+        // Arrange
+        let mut piece = PieceModel::new(
+            "2x3 End Hole".to_string(),
+            array2D!([1, 1, 0], [1, 1, 1]),
+            3,
+            true,
+        );
+
+        // Act
+        piece.set_flippable(false);
+
+        // Assert
+        assert_eq!(4, piece.unique_orientations().len());
+    }
+
+    #[test]
+    fn new_trims_padded_orientation() {
+        for piece in create_piece_models() {
+            // Arrange
+            // Pad the orientation with a row of zeros above and a column of zeros to the left
+            let cols = piece.initial_orientation.shape().cols;
+            let mut above = Array2D::repeat_row(&vec![0; cols], 1);
+            above.append_array(piece.initial_orientation.clone(), Axes::X);
+            let mut padded = Array2D::repeat_row(&[0], above.shape().rows);
+            padded.append_array(above, Axes::Y);
+
+            // Act
+            let padded_piece = PieceModel::new(
+                piece.name.clone(),
+                padded,
+                piece.max_rotations,
+                piece.is_flippable,
+            );
+
+            // Assert
+            assert_eq!(piece.initial_orientation, padded_piece.initial_orientation);
+            assert_eq!(
+                piece.unique_orientations(),
+                padded_piece.unique_orientations()
+            );
+        }
+    }
+
+    #[test]
+    fn state_machine_yields_each_orientation_once() {
+        for mut piece in create_piece_models() {
+            // Arrange
+            let expected_result = piece.unique_orientations();
+
+            // Act
+            let orientations = piece.collect_state_machine_orientations();
+
+            // Assert
+            let mut distinct = orientations.clone();
+            distinct.sort();
+            distinct.dedup();
+            assert_eq!(orientations.len(), distinct.len(), "{}", piece.name);
+            assert_eq!(expected_result, orientations, "{}", piece.name);
+        }
+    }
+
+    #[test]
+    fn piece_board_position() {
+        // Arrange
+        let mut piece = PieceModel::new(
+            "2x4 L".to_string(),
+            array2D!([0, 0, 0, 1], [1, 1, 1, 1]),
+            3,
+            true,
+        );
+        piece.next_unique_orientation();
+
+        // Act
+        piece.set_board_position(Some((2, 4)));
+
+        // Assert
+        assert_eq!(Some((2, 1)), piece.board_position());
     }
 
     #[test]
@@ -397,9 +1062,27 @@ mod tests {
         }
 
         // Assert
-        assert_eq!(piece.rotation_count, 3);
-        assert_eq!(piece.has_flipped, true);
-        assert_eq!(piece.orientation_exhausted, true);
+        assert_eq!(piece.rotation_count(), 3);
+        assert!(piece.has_flipped());
+        assert!(piece.orientation_exhausted);
+    }
+
+    #[test]
+    fn piece_board_position_covered_cells() {
+        // Arrange
+        let mut piece = PieceModel::new(
+            "2x4 Zig Zag".to_string(),
+            array2D!([0, 0, 1, 1], [1, 1, 1, 0]),
+            3,
+            true,
+        );
+        piece.set_board_position(Some((2, 3)));
+
+        // Act
+        let cells = piece.get_piece_board_position().covered_cells();
+
+        // Assert
+        assert_eq!(vec![(2, 5), (2, 6), (3, 3), (3, 4), (3, 5)], cells);
     }
 
     #[test]
@@ -421,6 +1104,6 @@ mod tests {
 
         // Assert
         assert_eq!(piece.translation_count, 2);
-        assert_eq!(piece.is_translation_exhausted(), true);
+        assert!(piece.is_translation_exhausted());
     }
 }
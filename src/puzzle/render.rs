@@ -0,0 +1,406 @@
+use crate::error::DfError;
+use crate::utils::array_2d::{Array2D, Axes};
+
+use super::{
+    board::{BoardModel, CalendarVariant},
+    piece::{self, PieceBoardPosition, PieceModel},
+    solver::SolverSingleThreaded,
+};
+
+/// Character used for empty cells.
+const EMPTY_CHAR: char = '.';
+
+/// Character used for reserved cells (blocked cells and the date).
+const RESERVED_CHAR: char = '#';
+
+/// Spacing between grids rendered side by side.
+const GUTTER: &str = "   ";
+
+/// The fill of each piece in `solution_to_svg`.
+const SVG_PIECE_COLOURS: [&str; 8] = [
+    "#e6194b", "#3cb44b", "#ffe119", "#0082c8", "#f58230", "#911eb4", "#46f0f0", "#f032e6",
+];
+
+/// The fill of cells outside the board in `solution_to_svg`.
+const SVG_BLOCKED_COLOUR: &str = "#ffffff";
+
+/// Finds the first solution for the specified date and returns it rendered as a grid of
+/// characters, one line per board row. Each piece is drawn with its own letter and the
+/// reserved cells are drawn with `#`.
+///
+/// # Arguments
+/// * `day` - The day of the date to be solved.
+/// * `month` - The month of the date to be solved.
+pub fn solve_and_render(day: usize, month: usize) -> Result<String, DfError> {
+    let board = BoardModel::try_new(day, month)?;
+    let mut solver = SolverSingleThreaded::try_new(day, month)?;
+    let names: Vec<String> = solver
+        .get_pieces()
+        .iter()
+        .map(|piece| piece.get_name().to_string())
+        .collect();
+
+    let solution = solver.next_solution().ok_or(DfError::NoSolution)?;
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+
+    Ok(render_solution(board.get_board_layout(), &names, &solution))
+}
+
+/// Returns the pieces drawn on top of the board layout as a grid of characters.
+/// Pieces are given the letters `A`, `B`, `C`... in the order of `names`.
+///
+/// # Arguments
+/// * `board_layout` - The board layout before any piece is placed.
+/// * `names` - The names of all the pieces, used to give each piece a consistent letter.
+/// * `solution` - The pieces on the board.
+pub fn render_solution(
+    board_layout: &Array2D,
+    names: &[&str],
+    solution: &[PieceBoardPosition],
+) -> String {
+    let shape = board_layout.shape();
+    let mut grid: Vec<Vec<char>> = (0..shape.rows)
+        .map(|row| {
+            (0..shape.cols)
+                .map(|col| {
+                    if board_layout.get(row, col) == 0 {
+                        EMPTY_CHAR
+                    } else {
+                        RESERVED_CHAR
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    for placement in solution {
+        let piece_index = names
+            .iter()
+            .position(|&name| name == placement.get_name())
+            .unwrap_or(0) as u8;
+        let letter = (b'A' + piece_index % 26) as char;
+
+        for (row, col) in placement.covered_cells() {
+            grid[row][col] = letter;
+        }
+    }
+
+    grid.iter()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns two solutions of the same board rendered as grids next to each other, separated by a
+/// gutter so each line holds the same board row of both solutions. Pieces are given the same
+/// letters in both grids, as in `render_solution`.
+///
+/// # Arguments
+/// * `board_layout` - The board layout before any piece is placed.
+/// * `names` - The names of all the pieces, used to give each piece a consistent letter.
+/// * `a` - The pieces of the solution drawn on the left.
+/// * `b` - The pieces of the solution drawn on the right.
+pub fn render_side_by_side(
+    board_layout: &Array2D,
+    names: &[&str],
+    a: &[PieceBoardPosition],
+    b: &[PieceBoardPosition],
+) -> String {
+    let grid_a = render_solution(board_layout, names, a);
+    let grid_b = render_solution(board_layout, names, b);
+
+    grid_a
+        .lines()
+        .zip(grid_b.lines())
+        .map(|(row_a, row_b)| format!("{}{}{}", row_a, GUTTER, row_b))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Returns the solution on the calendar board of the variant as an SVG document, with a square
+/// per board cell filled in the colour of the piece covering it and outlined by thin grid lines.
+/// The cells left uncovered on the board, i.e. the day and month (and day of the week), are
+/// hatched, and the blocked cells outside the board are left white. Pieces are coloured by their
+/// index in the solution.
+///
+/// # Arguments
+/// * `variant` - The calendar board the solution was found on.
+/// * `solution` - The pieces on the board.
+/// * `cell_px` - The width and height of a board cell in pixels.
+pub fn solution_to_svg(
+    variant: CalendarVariant,
+    solution: &[PieceBoardPosition],
+    cell_px: u32,
+) -> String {
+    let calendar = variant.empty_layout();
+    let shape = calendar.shape();
+    let (width, height) = (shape.cols as u32 * cell_px, shape.rows as u32 * cell_px);
+
+    let mut fills: Vec<&str> = calendar
+        .data()
+        .iter()
+        .map(|&cell| {
+            if cell == 0 {
+                "url(#date)"
+            } else {
+                SVG_BLOCKED_COLOUR
+            }
+        })
+        .collect();
+    for (index, placement) in solution.iter().enumerate() {
+        for (row, col) in placement.covered_cells() {
+            fills[calendar.to_index(row, col)] = SVG_PIECE_COLOURS[index % SVG_PIECE_COLOURS.len()];
+        }
+    }
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+         viewBox=\"0 0 {width} {height}\">\n"
+    );
+    svg.push_str(&format!(
+        "<defs><pattern id=\"date\" width=\"{0}\" height=\"{0}\" patternUnits=\"userSpaceOnUse\">\
+         <path d=\"M0 {0} L{0} 0\" stroke=\"#3c3c3c\" stroke-width=\"1\"/></pattern></defs>\n",
+        (cell_px / 4).max(1)
+    ));
+    for (index, fill) in fills.iter().enumerate() {
+        let (row, col) = calendar.from_index(index);
+        svg.push_str(&format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{cell_px}\" height=\"{cell_px}\" fill=\"{fill}\" \
+             stroke=\"#000000\" stroke-width=\"0.5\"/>\n",
+            col as u32 * cell_px,
+            row as u32 * cell_px,
+        ));
+    }
+    svg.push_str("</svg>");
+
+    svg
+}
+
+/// Returns a step by step guide to building the solution, one instruction per piece, e.g.
+/// "Place the 2x4 L rotated 90° anticlockwise at row 2, column 1.". Rows and columns are counted
+/// from 1 at the top left of the board. How a standard piece is turned is described relative to
+/// its orientation in `piece::create_piece_models`; other pieces are only given a position.
+///
+/// # Arguments
+/// * `solution` - The pieces and their board positions which make up the solution.
+pub fn solution_to_instructions(solution: &[PieceBoardPosition]) -> Vec<String> {
+    let pieces = piece::create_piece_models();
+
+    solution
+        .iter()
+        .map(|placement| {
+            let (row, col) = placement.get_board_position();
+            let turn = pieces
+                .iter()
+                .find(|piece| piece.get_name() == placement.get_name())
+                .and_then(|piece| describe_orientation(piece, &placement.get_orienation()))
+                .map(|turn| format!(" {}", turn))
+                .unwrap_or_default();
+
+            format!(
+                "Place the {}{} at row {}, column {}.",
+                placement.get_name().trim_end_matches('.'),
+                turn,
+                row + 1,
+                col + 1
+            )
+        })
+        .collect()
+}
+
+/// Returns how the piece is turned from its initial orientation to reach the orientation, or
+/// `None` if the orientation is not one of the piece's orientations. Rotations are preferred
+/// over flips, and smaller rotations over larger ones.
+fn describe_orientation(piece_model: &PieceModel, orientation: &Array2D) -> Option<String> {
+    let initial_orientation = piece_model.unique_orientations().swap_remove(0);
+    let mut flipped_orientation = initial_orientation.clone();
+    flipped_orientation.flip(Axes::Y);
+
+    for (is_flipped, start) in [(false, initial_orientation), (true, flipped_orientation)] {
+        for rotations in 0..4 {
+            let mut candidate = start.clone();
+            candidate.rotate90(rotations);
+            if candidate != *orientation {
+                continue;
+            }
+
+            return Some(match (is_flipped, rotations) {
+                (false, 0) => "as shown".to_string(),
+                (true, 0) => "flipped over".to_string(),
+                (false, _) => format!("rotated {}° anticlockwise", rotations * 90),
+                (true, _) => format!("flipped over and rotated {}° anticlockwise", rotations * 90),
+            });
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array2D;
+    use crate::puzzle::piece::PieceModel;
+
+    #[test]
+    fn solve_and_render_valid_date() {
+        // Arrange & Act
+        let grid = solve_and_render(6, 10).unwrap();
+
+        // Assert
+        let lines: Vec<&str> = grid.lines().collect();
+        assert_eq!(7, lines.len());
+        assert!(lines.iter().all(|line| line.chars().count() == 7));
+        assert!(!grid.contains(EMPTY_CHAR));
+        // 6 blocked cells and the 2 date cells
+        assert_eq!(8, grid.matches(RESERVED_CHAR).count());
+    }
+
+    #[test]
+    fn solution_to_svg_draws_every_cell() {
+        // Arrange
+        let solution = SolverSingleThreaded::new(6, 10).next_solution().unwrap();
+
+        // Act
+        let svg = solution_to_svg(CalendarVariant::DragonFjord, &solution, 20);
+
+        // Assert
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>"));
+        assert!(svg.contains("viewBox=\"0 0 140 140\""));
+        assert_eq!(49, svg.matches("<rect").count());
+        assert_eq!(2, svg.matches("fill=\"url(#date)\"").count());
+        assert_eq!(
+            6,
+            svg.matches(&format!("fill=\"{}\"", SVG_BLOCKED_COLOUR))
+                .count()
+        );
+        for colour in SVG_PIECE_COLOURS {
+            assert!(svg.contains(&format!("fill=\"{}\"", colour)));
+        }
+    }
+
+    #[test]
+    fn solution_to_svg_draws_weekday_board() {
+        // Arrange
+        // A piece across the last two rows, which the standard board does not have
+        let mut piece = piece::create_piece_models()[0].clone();
+        piece.set_board_position(Some((6, 4)));
+        let solution = vec![piece.get_piece_board_position()];
+
+        // Act
+        let svg = solution_to_svg(CalendarVariant::WeekdayPlus, &solution, 20);
+
+        // Assert
+        assert!(svg.contains("viewBox=\"0 0 140 160\""));
+        assert_eq!(56, svg.matches("<rect").count());
+        assert_eq!(
+            6,
+            svg.matches(&format!("fill=\"{}\"", SVG_PIECE_COLOURS[0]))
+                .count()
+        );
+        assert_eq!(44, svg.matches("fill=\"url(#date)\"").count());
+        assert_eq!(
+            6,
+            svg.matches(&format!("fill=\"{}\"", SVG_BLOCKED_COLOUR))
+                .count()
+        );
+    }
+
+    #[test]
+    fn solve_and_render_invalid_date() {
+        // Arrange & Act
+        let result = solve_and_render(31, 4);
+
+        // Assert
+        assert_eq!(Err(DfError::InvalidDate { day: 31, month: 4 }), result);
+    }
+
+    #[test]
+    #[rustfmt::skip::macros(array2D)]
+    fn render_partial_solution() {
+        // Arrange
+        let board_layout = array2D!(
+            [1, 0, 0],
+            [0, 0, 0]
+        );
+        let mut piece = PieceModel::new("Bar".to_string(), array2D!([1, 1]), 2, false);
+        piece.set_board_position(Some((0, 1)));
+        let solution = vec![piece.get_piece_board_position()];
+        let expected_result = "#BB\n...";
+
+        // Act
+        let grid = render_solution(&board_layout, &["Foo", "Bar"], &solution);
+
+        // Assert
+        assert_eq!(expected_result, grid);
+    }
+
+    #[test]
+    fn render_two_solutions_side_by_side() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(6, 10);
+        solver.find_solution_set();
+        let names: Vec<&str> = solver
+            .get_pieces()
+            .iter()
+            .map(|piece| piece.get_name())
+            .collect();
+        let board_layout = BoardModel::new(6, 10).get_board_layout().clone();
+        let (a, b) = (&solver.get_solution_set()[0], &solver.get_solution_set()[1]);
+        let grid_a = render_solution(&board_layout, &names, a);
+        let grid_b = render_solution(&board_layout, &names, b);
+
+        // Act
+        let grid = render_side_by_side(&board_layout, &names, a, b);
+
+        // Assert
+        let lines: Vec<&str> = grid.lines().collect();
+        assert_eq!(7, lines.len());
+        for ((line, row_a), row_b) in lines.iter().zip(grid_a.lines()).zip(grid_b.lines()) {
+            assert_eq!(7 + GUTTER.len() + 7, line.len());
+            assert_eq!(row_a, &line[..7]);
+            assert_eq!(GUTTER, &line[7..7 + GUTTER.len()]);
+            assert_eq!(row_b, &line[7 + GUTTER.len()..]);
+        }
+    }
+
+    #[test]
+    fn solution_to_instructions_names_every_piece() {
+        // Arrange
+        let solution = SolverSingleThreaded::new(6, 10).next_solution().unwrap();
+
+        // Act
+        let instructions = solution_to_instructions(&solution);
+
+        // Assert
+        assert_eq!(solution.len(), instructions.len());
+        for (instruction, placement) in instructions.iter().zip(&solution) {
+            let (row, col) = placement.get_board_position();
+            assert!(instruction.starts_with("Place the "));
+            assert!(instruction.contains(placement.get_name().trim_end_matches('.')));
+            assert!(instruction.ends_with(&format!(" at row {}, column {}.", row + 1, col + 1)));
+            assert_eq!(placement.is_flipped(), instruction.contains("flipped over"));
+        }
+    }
+
+    #[test]
+    fn solution_to_instructions_describes_rotation() {
+        // Arrange
+        let mut piece = piece::create_piece_models()[5].clone();
+        piece.next_unique_orientation();
+        while piece.current_orientation_index() != 1 {
+            piece.next_unique_orientation();
+        }
+        piece.set_board_position(Some((1, 0)));
+
+        // Act
+        let instructions = solution_to_instructions(&[piece.get_piece_board_position()]);
+
+        // Assert
+        assert_eq!(
+            vec!["Place the 2x4 L rotated 90° anticlockwise at row 2, column 1."],
+            instructions
+        );
+    }
+}
@@ -1,180 +1,490 @@
-use super::board::{self, BoardModel};
-use super::piece::{self, PieceBoardPosition, PieceModel};
+use super::board::{
+    self, BoardModel, CalendarVariant, PlacementCheck, PlacementTable, ValidityCache, Weekday,
+};
+use super::piece::{self, PieceBoardPosition, PieceModel, PieceState};
+use crate::error::DfError;
+use crate::utils::array_2d::{Array2D, Shape};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
 use std::mem;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{self, Receiver, Sender};
 use std::thread::{self};
+use std::time::{Duration, Instant};
 
-#[derive(Clone)]
-pub struct SolverSingleThreaded {
-    pieces: [PieceModel; 8],
-    board: BoardModel,
-    solution_set: Vec<Vec<PieceBoardPosition>>,
+/// The outcome of a single step of the depth first search.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum SearchStep {
+    /// A piece was placed and the board is not yet complete.
+    Placed,
+    /// A piece was placed and the board is complete.
+    Solved,
+    /// The last placed piece was removed from the board.
+    Backtracked,
+    /// Every combination has been searched.
+    Exhausted,
 }
 
-impl SolverSingleThreaded {
-    pub fn new(day: usize, month: usize) -> SolverSingleThreaded {
-        SolverSingleThreaded {
-            pieces: piece::create_piece_models(),
-            board: BoardModel::new(day, month),
-            solution_set: Vec::new(),
-        }
-    }
+/// The events reported by `SolverSingleThreaded::find_solution_set_with_progress` as the search
+/// runs.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SolverEvent {
+    /// The piece at `index` was placed, leaving `depth` pieces on the board.
+    PiecePlaced { index: usize, depth: usize },
+    /// The last placed piece was removed, leaving `depth` pieces on the board.
+    Backtracked { depth: usize },
+    /// A solution was found, making `count` solutions in the solution set.
+    SolutionFound { count: usize },
+}
 
-    /// Returns an immutable reference to pieces field.
-    pub fn get_pieces(&self) -> &[PieceModel; 8] {
-        &self.pieces
-    }
+/// The state of the search after `SolverSingleThreaded::find_first_solution_stepped`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct StepResult {
+    /// The board layout after the last step.
+    pub board_layout: Array2D,
+    /// Whether the last step completed the board.
+    pub is_solved: bool,
+    /// The number of steps made, which is less than the maximum if the board was completed or
+    /// the search was exhausted.
+    pub steps: usize,
+}
 
-    /// Returns an immutable reference to the solution_set field.
-    pub fn get_solution_set(&self) -> &Vec<Vec<PieceBoardPosition>> {
-        &self.solution_set
+/// Weights which bias `SolverSingleThreaded::find_weighted_solution` towards placements of
+/// pieces over preferred board cells. The weight of a placement is the sum of the weights given
+/// to its piece for each cell it covers, and cells without a weight count as 0.
+#[derive(Debug, Default, Clone)]
+pub struct PlacementWeights {
+    weights: HashMap<(String, (usize, usize)), i64>,
+}
+
+impl PlacementWeights {
+    pub fn new() -> PlacementWeights {
+        PlacementWeights::default()
     }
 
-    /// Returns a solution set for solver.
+    /// Returns the weights with a weight added for the piece covering the board cell. A
+    /// negative weight makes placements covering the cell less preferred.
     ///
-    /// Iterates through all possible combinations and appends valid
-    ///  solutions to the `solution_set` vector.
-    pub fn find_solution_set(&mut self) {
-        // Create memento to handle state
-        let mut start_index = 0;
-        let mut solver_history: Vec<usize> = Vec::new();
-
-        loop {
-            // Flag to determine when to go back to previous state
-            let mut restore_last_state = true;
-
-            // Get next available board position
-            let board_position = board::next_board_position(self.board.get_board_layout());
+    /// # Arguments
+    /// * `piece_name` - The name of the piece.
+    /// * `cell` - The row and column of the board cell.
+    /// * `weight` - The weight added to placements of the piece covering the cell.
+    pub fn with_weight(
+        mut self,
+        piece_name: &str,
+        cell: (usize, usize),
+        weight: i64,
+    ) -> PlacementWeights {
+        *self
+            .weights
+            .entry((piece_name.to_string(), cell))
+            .or_insert(0) += weight;
+        self
+    }
 
-            // Get next eligible piece to be placed
-            'piece_loop: for index in start_index..self.get_pieces().len() {
-                let piece = &mut self.pieces[index];
+    /// Returns the weight of the placement.
+    pub fn weight_of(&self, placement: &PieceBoardPosition) -> i64 {
+        placement
+            .covered_cells()
+            .into_iter()
+            .filter_map(|cell| self.weights.get(&(placement.get_name().to_string(), cell)))
+            .sum()
+    }
+}
 
-                if !piece.is_used() {
-                    while !piece.is_exhausted() {
-                        if self.board.is_piece_valid(board_position, piece) {
-                            // Set flag to indicate piece is used
-                            piece.set_used(true);
+/// What `solve_auto` searches for.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SolveMode {
+    /// The first solution found.
+    First,
+    /// The number of unique solutions.
+    Count,
+    /// Every unique solution.
+    All,
+}
 
-                            // Save current board state
-                            self.board.generate_memento();
+/// The result of `solve_auto` for each `SolveMode`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SolveOutcome {
+    First(Option<Vec<PieceBoardPosition>>),
+    Count(usize),
+    All(Vec<Vec<PieceBoardPosition>>),
+}
 
-                            // Update board state
-                            self.board.add_piece_to_board(piece);
+/// Solves the date with the solver best suited to the mode.
+///
+/// * `First` uses `SolverSingleThreaded`, as its search can stop as soon as the board is
+///   complete. The multi threaded solver always searches every combination.
+/// * `Count` and `All` use `SolverMultiThreaded`, as the whole search is needed and it splits the
+///   search across a thread per piece. With a single core it takes about as long as
+///   `SolverSingleThreaded`.
+///
+/// Solutions for `Count` and `All` have duplicates removed, and `All` returns them sorted.
+///
+/// # Arguments
+/// * `day` - The day of the date to be solved.
+/// * `month` - The month of the date to be solved.
+/// * `mode` - What to search for.
+pub fn solve_auto(day: usize, month: usize, mode: SolveMode) -> SolveOutcome {
+    match mode {
+        SolveMode::First => {
+            SolveOutcome::First(SolverSingleThreaded::new(day, month).next_solution())
+        }
+        SolveMode::Count | SolveMode::All => {
+            let mut solver = SolverMultiThreaded::new(day, month);
+            solver.find_solution_set();
+            solver.remove_duplicates();
 
-                            // Save current state of solver
-                            solver_history.push(index);
+            if mode == SolveMode::Count {
+                SolveOutcome::Count(solver.get_solution_set().len())
+            } else {
+                SolveOutcome::All(solver.solution_set)
+            }
+        }
+    }
+}
 
-                            // Set loop flag
-                            restore_last_state = false;
+/// Solves every valid date in the given mode and returns the dates with the time each took,
+/// slowest first. The times depend on the machine and how the search is ordered, so they show
+/// which dates are slow for the solver rather than which are hard for a person.
+///
+/// # Arguments
+/// * `mode` - What to search for on each date.
+pub fn rank_dates_by_solve_time(mode: SolveMode) -> Vec<((usize, usize), Duration)> {
+    let mut times: Vec<((usize, usize), Duration)> = board::valid_dates()
+        .into_iter()
+        .map(|(day, month)| {
+            let start_time = Instant::now();
+            solve_auto(day, month, mode);
+            ((day, month), start_time.elapsed())
+        })
+        .collect();
 
-                            // Reset start_index
-                            start_index = 0;
+    times.sort_by_key(|&(_, time)| std::cmp::Reverse(time));
+    times
+}
 
-                            break 'piece_loop;
-                        } else {
-                            piece.next_unique_orientation();
-                        }
-                    }
+/// Returns every valid date without a solution, in date order. The physical puzzle can be solved
+/// for every date, so this should be empty. The search for each date stops at its first solution.
+pub fn unsolvable_dates() -> Vec<(usize, usize)> {
+    board::valid_dates()
+        .into_iter()
+        .filter(|&(day, month)| {
+            SolverSingleThreaded::new(day, month)
+                .next_solution()
+                .is_none()
+        })
+        .collect()
+}
 
-                    // Orientations Exhausted - reset piece
-                    piece.reset();
-                }
-            }
+/// Checks that every valid date has a solution, as the physical puzzle can be solved for every
+/// date (see `unsolvable_dates`).
+///
+/// # Panics!
+/// Will panic naming every date found without a solution.
+pub fn assert_all_dates_solvable() {
+    let unsolvable = unsolvable_dates();
 
-            // Check if board is complete. Add solution to solution set if it is complete.
-            if board::is_board_complete(self.board.get_board_layout()) {
-                // A solutions has been found. Record solution
-                let mut solution: Vec<PieceBoardPosition> = Vec::new();
-                for piece in &self.pieces {
-                    solution.push(piece.get_piece_board_position());
-                }
+    assert!(
+        unsolvable.is_empty(),
+        "No solution was found for (day, month) {:?}",
+        unsolvable
+    );
+}
 
-                // Append solution to solution set
-                self.solution_set.push(solution);
+/// Returns the number of unique solutions of every valid date, keyed by `(day, month)`. Each
+/// date is searched in full by its own solver, so this takes a while.
+pub fn solve_all_dates() -> BTreeMap<(usize, usize), usize> {
+    board::valid_dates()
+        .into_iter()
+        .map(|(day, month)| {
+            let mut solver = SolverSingleThreaded::new(day, month);
+            solver.find_solution_set();
+            solver.remove_duplicates();
 
-                // Change flag to indicate to undo last move
-                restore_last_state = true;
-            }
+            ((day, month), solver.get_solution_set().len())
+        })
+        .collect()
+}
 
-            // If hasn't broken out, has exhausted all possibilities for current loop.
-            // Return to previous solver state or break from loop if finished searching.
-            if restore_last_state {
-                match solver_history.pop() {
-                    Some(x) => {
-                        start_index = x;
+/// Returns the ids (indices in `piece::create_piece_models`) of the pieces the date cannot be
+/// solved without. The standard pieces exactly cover the empty cells of a dated board, so every
+/// piece is critical. See `critical_pieces_on` for custom sets of pieces.
+///
+/// # Arguments
+/// * `day` - The day of the date to be solved.
+/// * `month` - The month of the date to be solved.
+pub fn critical_pieces(day: usize, month: usize) -> Vec<u8> {
+    critical_pieces_on(
+        BoardModel::new(day, month),
+        piece::create_piece_models().to_vec(),
+    )
+}
 
-                        // return to previous board position
-                        self.board.restore_from_memento();
+/// Returns the ids (indices in `pieces`) of the pieces which, if removed on their own, leave the
+/// board with no solution. When the pieces cover more cells than the board has, a solution does
+/// not use every piece and the pieces which are not critical are redundant.
+///
+/// # Arguments
+/// * `board` - The board to be filled.
+/// * `pieces` - The puzzle pieces used to fill the board.
+pub fn critical_pieces_on(board: BoardModel, pieces: Vec<PieceModel>) -> Vec<u8> {
+    (0..pieces.len())
+        .filter(|&id| {
+            let mut remaining = pieces.clone();
+            remaining.remove(id);
+            SolverSingleThreaded::with_board(board.clone(), remaining)
+                .next_solution()
+                .is_none()
+        })
+        .map(|id| id as u8)
+        .collect()
+}
 
-                        // Remove flag indicating piece is used
-                        self.pieces[start_index].set_used(false);
-                        self.pieces[start_index].set_board_position(None);
+/// Returns the dates which can only be solved by flipping at least one piece over, i.e. the
+/// dates with no solution when every piece keeps the same side up. Every date is searched with
+/// and without flips, stopping at the first solution.
+pub fn dates_requiring_flips() -> Vec<(usize, usize)> {
+    board::valid_dates()
+        .into_iter()
+        .filter(|&(day, month)| {
+            SolverSingleThreaded::new(day, month)
+                .without_flips()
+                .next_solution()
+                .is_none()
+                && SolverSingleThreaded::new(day, month)
+                    .next_solution()
+                    .is_some()
+        })
+        .collect()
+}
 
-                        // Get next unique orientation of piece
-                        self.pieces[start_index].next_unique_orientation();
-                    }
-                    // Completed Search, break from outer loop
-                    None => break,
-                }
-            }
-        }
-    }
+/// Returns the number of unique solutions of the date on each of the calendar variants, in the
+/// order the variants are given. Each variant is solved on its own board. Returns an error if a
+/// variant shows the days of the week but no weekday is given.
+///
+/// # Arguments
+/// * `day` - The day of the date to be solved.
+/// * `month` - The month of the date to be solved.
+/// * `weekday` - The day of the week of the date, needed by the variants which show the days of
+///   the week (see `BoardModel::new_variant`).
+/// * `variants` - The calendar variants to solve the date on.
+///
+/// The standard pieces are used on every variant, so a variant with more empty cells than they
+/// cover, like `CalendarVariant::WeekdayPlus`, has no solutions.
+pub fn solve_across_variants(
+    day: usize,
+    month: usize,
+    weekday: Option<Weekday>,
+    variants: &[CalendarVariant],
+) -> Result<Vec<(CalendarVariant, usize)>, DfError> {
+    variants
+        .iter()
+        .map(|&variant| {
+            let board = BoardModel::new_variant(day, month, variant, weekday)?;
+            let mut solver =
+                SolverSingleThreaded::with_board(board, piece::create_piece_models().to_vec());
+            solver.find_solution_set();
+            solver.remove_duplicates();
 
-    pub fn remove_duplicates(&mut self) {
-        self.solution_set.sort();
-        self.solution_set.dedup();
-    }
+            Ok((variant, solver.get_solution_set().len()))
+        })
+        .collect()
 }
 
-pub struct SolverMultiThreaded {
-    pieces: [PieceModel; 8],
+/// A solver which searches for solutions one at a time. The search can be paused between
+/// solutions and continued later. With the `serde` feature the whole search state can be
+/// saved, so a search can also be continued after the process restarts.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SolverSingleThreaded {
+    pieces: Vec<PieceModel>,
     board: BoardModel,
     solution_set: Vec<Vec<PieceBoardPosition>>,
+
+    // Search state
+    start_index: usize,
+    // Index of each placed piece and its state before it was placed
+    solver_history: Vec<(usize, PieceState)>,
+    validity_cache: Option<ValidityCache>,
+    dead_states: Option<HashSet<Array2D>>,
+    symmetry_reduction: bool,
+    // Area of each unused piece, or 0 once the piece is placed
+    remaining_areas: Vec<usize>,
+    // Reused for the board layout of every placement checked on boards too large for a mask
+    placement_scratch: Array2D,
+    // Number of backtracks made with each number of pieces placed
+    backtrack_depths: [usize; 9],
 }
 
-impl SolverMultiThreaded {
-    pub fn new(day: usize, month: usize) -> SolverMultiThreaded {
-        SolverMultiThreaded {
-            pieces: piece::create_piece_models(),
-            board: BoardModel::new(day, month),
+impl SolverSingleThreaded {
+    pub fn new(day: usize, month: usize) -> SolverSingleThreaded {
+        SolverSingleThreaded::with_board(
+            BoardModel::new(day, month),
+            piece::create_piece_models().to_vec(),
+        )
+    }
+
+    /// Returns a new solver for the date, or an error if the day and month are not a valid date.
+    pub fn try_new(day: usize, month: usize) -> Result<SolverSingleThreaded, DfError> {
+        Ok(SolverSingleThreaded::with_board(
+            BoardModel::try_new(day, month)?,
+            piece::create_piece_models().to_vec(),
+        ))
+    }
+
+    /// Returns a new solver for a custom board and set of puzzle pieces.
+    ///
+    /// # Arguments
+    /// * `board` - The board to be filled.
+    /// * `pieces` - The puzzle pieces used to fill the board.
+    pub fn with_board(board: BoardModel, pieces: Vec<PieceModel>) -> SolverSingleThreaded {
+        let placement_scratch = board.get_board_layout().clone();
+        let remaining_areas = pieces.iter().map(|piece| piece.area()).collect();
+        SolverSingleThreaded {
+            pieces,
+            board,
             solution_set: Vec::new(),
+            start_index: 0,
+            solver_history: Vec::new(),
+            validity_cache: None,
+            dead_states: None,
+            symmetry_reduction: false,
+            remaining_areas,
+            placement_scratch,
+            backtrack_depths: [0; 9],
         }
     }
 
-    /// Creates worker threads to find solutions for a smaller subset of pieces
-    pub fn find_solution_set(&mut self) {
-        let (sender, receiver): (
-            Sender<Vec<PieceBoardPosition>>,
-            Receiver<Vec<PieceBoardPosition>>,
-        ) = mpsc::channel();
-
-        for index in 0..self.pieces.len() {
-            let board_thread = self.board.clone();
-            let sender_thread = sender.clone();
+    /// Returns a new solver for a custom board which uses only some of the standard puzzle
+    /// pieces, or an error if a piece id is unknown or the pieces do not cover exactly the
+    /// number of empty cells of the board.
+    ///
+    /// # Arguments
+    /// * `board` - The board to be filled.
+    /// * `piece_ids` - The indices of the pieces to be used in `piece::create_piece_models`.
+    pub fn with_piece_subset(
+        board: BoardModel,
+        piece_ids: &[u8],
+    ) -> Result<SolverSingleThreaded, DfError> {
+        let all_pieces = piece::create_piece_models();
+        let pieces = piece_ids
+            .iter()
+            .map(|&id| {
+                all_pieces
+                    .get(id as usize)
+                    .cloned()
+                    .ok_or(DfError::UnknownPiece { id })
+            })
+            .collect::<Result<Vec<PieceModel>, DfError>>()?;
 
-            thread::spawn(move || {
-                let mut worker = Worker::create_new_worker(board_thread, sender_thread);
-                worker.find_solution_set(index, index + 1);
+        let board_cells = board::get_all_empty_positions(board.get_board_layout()).len();
+        let piece_cells: usize = pieces.iter().map(|piece| piece.area()).sum();
+        if board_cells != piece_cells {
+            return Err(DfError::AreaMismatch {
+                board_cells,
+                piece_cells,
             });
         }
 
-        // It is possible to iterate over the receiver. The receiver will block
-        // when the iterator asks for the next value. When the channel is closed,
-        // the iterator will return None and end.
-        // Issue if the original sender is cloned, as the original sender is never dropped.
-        // Need to drop the original sender to prevent an infinite loop.
-        // https://nickymeuleman.netlify.app/blog/multithreading-rust
-        // drop the original sender, else the channel will remain open, causing the receiver to infinitely wait
-        mem::drop(sender);
+        Ok(SolverSingleThreaded::with_board(board, pieces))
+    }
 
-        for solution in receiver {
-            self.solution_set.push(solution)
+    /// Returns the solver with a cache of piece validity checks enabled. The same placement is
+    /// often checked on the same board layout after different pieces have filled the same cells,
+    /// and the cache answers the repeated checks without searching for holes again. A lookup
+    /// costs more than the check, so the search is slower with the cache (see `ValidityCache`).
+    pub fn with_validity_cache(mut self) -> SolverSingleThreaded {
+        self.validity_cache = Some(ValidityCache::new());
+        self
+    }
+
+    /// Returns the validity cache, if it is enabled.
+    pub fn get_validity_cache(&self) -> Option<&ValidityCache> {
+        self.validity_cache.as_ref()
+    }
+
+    /// Returns the solver with flipping disabled for every piece, so each piece keeps the same
+    /// side up and is only rotated. Should be called before the search begins.
+    pub fn without_flips(mut self) -> SolverSingleThreaded {
+        for piece in &mut self.pieces {
+            piece.set_flippable(false);
         }
+        self
+    }
+
+    /// Returns the solver with symmetry reduction enabled or disabled. For a board which is the
+    /// same when mirrored from left to right, every solution has a mirror image which is also a
+    /// solution, with every asymmetric piece flipped over. While enabled, the first piece which
+    /// can be flipped is kept the same side up, so only one solution of each mirrored pair is
+    /// searched for and the search space is halved. `full_solution_set` recovers the mirrored
+    /// solutions. Should be called before the search begins.
+    ///
+    /// The Dragon Fjord board is not symmetric, so this is only correct for custom boards.
+    ///
+    /// # Arguments
+    /// * `enabled` - A boolean indicating if the mirrored solutions should be skipped.
+    pub fn with_symmetry_reduction(mut self, enabled: bool) -> SolverSingleThreaded {
+        self.symmetry_reduction = enabled;
+        if enabled {
+            if let Some(piece) = self.pieces.iter_mut().find(|piece| piece.is_flippable()) {
+                piece.set_flippable(false);
+            }
+        }
+        self
+    }
+
+    /// Returns the solution set with the mirror image of every solution added if symmetry
+    /// reduction is enabled, or a copy of the solution set if not.
+    pub fn full_solution_set(&self) -> Vec<Vec<PieceBoardPosition>> {
+        let mut solutions = self.solution_set.clone();
+        if !self.symmetry_reduction {
+            return solutions;
+        }
+
+        let board_cols = self.board.get_board_layout().shape().cols;
+        for solution in &self.solution_set {
+            solutions.push(
+                solution
+                    .iter()
+                    .map(|placement| {
+                        self.pieces
+                            .iter()
+                            .find(|piece| piece.get_name() == placement.get_name())
+                            .unwrap()
+                            .mirrored_placement(placement, board_cols)
+                    })
+                    .collect(),
+            );
+        }
+
+        solutions
+    }
+
+    /// Returns the solver with the collection of dead states enabled. A dead state is a board
+    /// layout at which the hole check pruned the search, i.e. a piece fit but left unreachable
+    /// holes. While enabled, every placement is checked directly rather than through the validity
+    /// cache.
+    pub fn with_dead_states(mut self) -> SolverSingleThreaded {
+        self.dead_states = Some(HashSet::new());
+        self
+    }
+
+    /// Returns the distinct dead states found so far in sorted order. Returns an empty `Vec` if
+    /// the collection of dead states is not enabled.
+    pub fn dead_states(&self) -> Vec<Array2D> {
+        let mut dead_states: Vec<Array2D> = match &self.dead_states {
+            Some(dead_states) => dead_states.iter().cloned().collect(),
+            None => Vec::new(),
+        };
+        dead_states.sort();
+        dead_states
     }
 
     /// Returns an immutable reference to pieces field.
-    pub fn get_pieces(&self) -> &[PieceModel; 8] {
+    pub fn get_pieces(&self) -> &[PieceModel] {
         &self.pieces
     }
 
@@ -183,116 +493,2409 @@ impl SolverMultiThreaded {
         &self.solution_set
     }
 
-    pub fn remove_duplicates(&mut self) {
-        self.solution_set.sort();
-        self.solution_set.dedup();
+    /// Returns a solution set for solver.
+    ///
+    /// Iterates through all possible combinations and appends valid
+    ///  solutions to the `solution_set` vector.
+    pub fn find_solution_set(&mut self) {
+        self.find_solution_set_cancellable(&AtomicBool::new(false));
     }
-}
 
-struct Worker {
-    pieces: [PieceModel; 8],
-    board: BoardModel,
-    sender: Sender<Vec<PieceBoardPosition>>,
-}
+    /// Searches for solutions until the search is complete or `cancel` is set, appending any
+    /// found to the `solution_set` vector. Returns true if the search is complete, or false if it
+    /// was cancelled, in which case calling it again continues the search from where it was left.
+    ///
+    /// # Arguments
+    /// * `cancel` - The flag checked before every step of the search, e.g. set by another thread
+    ///   to stop the search.
+    pub fn find_solution_set_cancellable(&mut self, cancel: &AtomicBool) -> bool {
+        while !cancel.load(Ordering::Relaxed) {
+            match self.step() {
+                // Append solution to solution set
+                SearchStep::Solved => self.solution_set.push(self.current_placements()),
+                SearchStep::Exhausted => return true,
+                SearchStep::Placed | SearchStep::Backtracked => (),
+            }
+        }
 
-impl Worker {
-    /// Create a brand new worker
-    pub fn create_new_worker(board: BoardModel, sender: Sender<Vec<PieceBoardPosition>>) -> Worker {
-        Worker {
-            pieces: piece::create_piece_models(),
-            board,
-            sender,
+        false
+    }
+
+    /// Returns the number of solutions, continuing the search from where it was left. The
+    /// solutions are counted as they are found rather than collected into the `solution_set`
+    /// vector, so no placements are allocated. Duplicate solutions are counted.
+    pub fn count_solutions(&mut self) -> usize {
+        let mut count = 0;
+        loop {
+            match self.step() {
+                SearchStep::Solved => count += 1,
+                SearchStep::Exhausted => return count,
+                SearchStep::Placed | SearchStep::Backtracked => (),
+            }
         }
     }
 
-    /// Returns a solution set for solver.
-    ///
-    /// Iterates through all possible combinations and appends valid
-    ///  solutions to the `solution_set` vector.
-    pub fn find_solution_set(&mut self, mut start_index: usize, mut end_index: usize) {
-        // Create memento to handle state
-        let mut solver_history: Vec<(usize, usize)> = Vec::new();
+    /// Searches for the first solution, stopping as soon as the board is complete. The solution
+    /// is returned rather than appended to the `solution_set` vector. Calling it again continues
+    /// the search and returns the next solution. Returns `None` if the board has no (more)
+    /// solutions.
+    pub fn find_first_solution(&mut self) -> Option<Vec<PieceBoardPosition>> {
+        self.next_solution()
+    }
+
+    /// Returns an iterator over the solutions, each found lazily as the iterator is advanced.
+    /// The search state is held by the solver, so each call to `next` continues the search from
+    /// the last solution and the solutions are never collected into the `solution_set` vector.
+    pub fn solutions(mut self) -> impl Iterator<Item = Vec<PieceBoardPosition>> {
+        std::iter::from_fn(move || self.next_solution())
+    }
 
+    /// Returns a solution set for solver like `find_solution_set`, calling `on_event` every time
+    /// a piece is placed or removed and every time a solution is found, e.g. to show the progress
+    /// of a long search.
+    ///
+    /// # Arguments
+    /// * `on_event` - The closure called with each event of the search.
+    pub fn find_solution_set_with_progress(&mut self, mut on_event: impl FnMut(SolverEvent)) {
         loop {
-            // Flag to determine when to go back to previous state
-            let mut restore_last_state = true;
+            let step = self.step();
+            let depth = self.solver_history.len();
 
-            // Get next available board position
-            let board_position = board::next_board_position(self.board.get_board_layout());
+            match step {
+                SearchStep::Placed | SearchStep::Solved => {
+                    let (index, _) = *self.solver_history.last().unwrap();
+                    on_event(SolverEvent::PiecePlaced { index, depth });
 
-            // Get next eligible piece to be placed
-            'piece_loop: for index in start_index..end_index {
-                let piece = &mut self.pieces[index];
+                    if step == SearchStep::Solved {
+                        self.solution_set.push(self.current_placements());
+                        on_event(SolverEvent::SolutionFound {
+                            count: self.solution_set.len(),
+                        });
+                    }
+                }
+                SearchStep::Backtracked => on_event(SolverEvent::Backtracked { depth }),
+                SearchStep::Exhausted => break,
+            }
+        }
+    }
 
-                if !piece.is_used() {
-                    while !piece.is_exhausted() {
-                        if self.board.is_piece_valid(board_position, piece) {
-                            // Set flag to indicate piece is used
-                            piece.set_used(true);
+    /// Searches for solutions for at most `time_limit`, appending any found to the
+    /// `solution_set` vector. Returns true if the search is complete, or false if the time ran
+    /// out, in which case calling it again continues the search from where it was left.
+    pub fn find_solution_set_within(&mut self, time_limit: Duration) -> bool {
+        let start_time = Instant::now();
 
-                            // Save current board state
-                            self.board.generate_memento();
+        while start_time.elapsed() < time_limit {
+            match self.step() {
+                SearchStep::Solved => self.solution_set.push(self.current_placements()),
+                SearchStep::Exhausted => return true,
+                SearchStep::Placed | SearchStep::Backtracked => (),
+            }
+        }
 
-                            // Update board state
-                            self.board.add_piece_to_board(piece);
+        false
+    }
 
-                            // Save current state of solver
-                            solver_history.push((index, end_index));
+    /// Makes at most `max_steps` steps of the search, where each step places a piece or removes
+    /// the last placed piece, and returns the board after the last step. The steps stop early
+    /// if the board is completed or every combination has been searched. Calling it again
+    /// continues from where the last call stopped, so the search can be followed one step at a
+    /// time.
+    ///
+    /// # Arguments
+    /// * `max_steps` - The maximum number of steps to make.
+    pub fn find_first_solution_stepped(&mut self, max_steps: usize) -> StepResult {
+        let mut steps = 0;
+        let mut is_solved = false;
 
-                            // Set loop flag
-                            restore_last_state = false;
+        while steps < max_steps && !is_solved {
+            steps += 1;
+            match self.step() {
+                SearchStep::Solved => is_solved = true,
+                SearchStep::Exhausted => break,
+                SearchStep::Placed | SearchStep::Backtracked => (),
+            }
+        }
 
-                            // Reset start_index & end_index
-                            start_index = 0;
-                            end_index = self.pieces.len();
+        StepResult {
+            board_layout: self.board.get_board_layout().clone(),
+            is_solved,
+            steps,
+        }
+    }
 
-                            break 'piece_loop;
-                        } else {
-                            piece.next_unique_orientation();
-                        }
-                    }
+    /// Searches for a solution, trying the placements in order of their weight, highest first.
+    /// The placements with a positive weight are decided on first, from the highest weight
+    /// down: each one which still fits is either used or left out of the rest of the search.
+    /// The highest weighted placement which is in any solution is therefore always used,
+    /// wherever it is on the board. The rest of the board is filled from the top left as
+    /// usual, trying placements with a negative weight last. Returns `None` if the board has no
+    /// solution. The search starts from the pieces currently placed and does not change the
+    /// state of the solver. Panics if the board has more than 64 cells.
+    ///
+    /// # Arguments
+    /// * `weights` - The weights of the preferred placements.
+    pub fn find_weighted_solution(
+        &self,
+        weights: &PlacementWeights,
+    ) -> Option<Vec<PieceBoardPosition>> {
+        let table = PlacementTable::new(&self.pieces, &self.board);
+        let placement_weights: Vec<Vec<i64>> = (0..self.pieces.len())
+            .map(|index| {
+                table
+                    .placements(index)
+                    .iter()
+                    .map(|(placement, _)| weights.weight_of(placement))
+                    .collect()
+            })
+            .collect();
+        // Every placement with a positive weight, as its piece index and index in the table
+        let mut preferred: Vec<(usize, usize)> = placement_weights
+            .iter()
+            .enumerate()
+            .flat_map(|(index, weights)| {
+                (0..weights.len())
+                    .filter(|&placement| weights[placement] > 0)
+                    .map(move |placement| (index, placement))
+            })
+            .collect();
+        preferred.sort_by_key(|&(index, placement)| {
+            std::cmp::Reverse(placement_weights[index][placement])
+        });
+        let shape = self.board.get_board_layout().shape().clone();
+        let (current_placements, mut piece_areas) = self.table_search_state();
+        let mut placements: Vec<Option<&PieceBoardPosition>> =
+            current_placements.iter().map(Option::as_ref).collect();
 
-                    // Orientations Exhausted - reset piece
-                    piece.reset();
-                }
+        SolverSingleThreaded::weighted_search(
+            &table,
+            &preferred,
+            &placement_weights,
+            &shape,
+            self.board.occupancy(),
+            &mut placements,
+            &mut piece_areas,
+        )
+    }
+
+    /// Decides on the highest weighted preferred placement which still fits, searching the rest
+    /// of the board with it and then without it. Once every preferred placement has been
+    /// decided on, tries the placements covering the top left empty cell in order of their
+    /// weight and searches the rest of the board after each one until a solution is found.
+    ///
+    /// # Arguments
+    /// * `table` - The placements of the pieces.
+    /// * `preferred` - The placements with a positive weight still to be decided on, highest
+    ///   weight first, as their piece index and index in `table`.
+    /// * `placement_weights` - The weight of each placement in `table`.
+    /// * `shape` - The shape of the board.
+    /// * `occupancy` - The occupancy mask of the board with the pieces placed so far.
+    /// * `placements` - The placement of each piece, or `None` if it is still to be placed.
+    /// * `piece_areas` - The area of each piece still to be placed, and 0 for placed pieces.
+    fn weighted_search<'a>(
+        table: &'a PlacementTable,
+        preferred: &[(usize, usize)],
+        placement_weights: &[Vec<i64>],
+        shape: &Shape,
+        occupancy: u64,
+        placements: &mut [Option<&'a PieceBoardPosition>],
+        piece_areas: &mut [usize],
+    ) -> Option<Vec<PieceBoardPosition>> {
+        let empty = table.board_mask() & !occupancy;
+        if empty == 0 {
+            return Some(
+                placements
+                    .iter()
+                    .flatten()
+                    .map(|&placement| placement.clone())
+                    .collect(),
+            );
+        }
+
+        // A preferred placement which no longer fits can never be used, so is skipped
+        let next_preferred = preferred.iter().position(|&(index, placement)| {
+            placements[index].is_none() && table.placements(index)[placement].1 & occupancy == 0
+        });
+        if let Some(position) = next_preferred {
+            let (index, placement) = preferred[position];
+            let (placement, mask) = &table.placements(index)[placement];
+            let rest = &preferred[position + 1..];
+
+            let area = mem::take(&mut piece_areas[index]);
+            let mut solution = None;
+            if board::is_area_coverable_mask(occupancy | mask, shape, piece_areas) {
+                placements[index] = Some(placement);
+                solution = SolverSingleThreaded::weighted_search(
+                    table,
+                    rest,
+                    placement_weights,
+                    shape,
+                    occupancy | mask,
+                    placements,
+                    piece_areas,
+                );
+                placements[index] = None;
             }
+            piece_areas[index] = area;
 
-            // Check if board is complete. Add solution to solution set if it is complete.
-            if board::is_board_complete(self.board.get_board_layout()) {
-                // A solutions has been found. Record solution
-                let mut solution: Vec<PieceBoardPosition> = Vec::new();
-                for piece in &self.pieces {
-                    solution.push(piece.get_piece_board_position());
+            return solution.or_else(|| {
+                SolverSingleThreaded::weighted_search(
+                    table,
+                    rest,
+                    placement_weights,
+                    shape,
+                    occupancy,
+                    placements,
+                    piece_areas,
+                )
+            });
+        }
+
+        // Every placement covering the top left empty cell which fits, as its weight, piece
+        // index and mask. The preferred placements have all been used or left out by now.
+        let cell_mask = empty & empty.wrapping_neg();
+        let mut candidates: Vec<(i64, usize, &'a PieceBoardPosition, u64)> = Vec::new();
+        for index in 0..placements.len() {
+            if placements[index].is_some() {
+                continue;
+            }
+            for ((placement, mask), &weight) in table
+                .placements(index)
+                .iter()
+                .zip(&placement_weights[index])
+            {
+                if weight <= 0 && mask & cell_mask != 0 && mask & occupancy == 0 {
+                    candidates.push((weight, index, placement, *mask));
                 }
+            }
+        }
+        candidates.sort_by_key(|&(weight, ..)| std::cmp::Reverse(weight));
 
-                // Send solution to receiver to be appended to solution set
-                self.sender.send(solution).unwrap();
+        for (_, index, placement, mask) in candidates {
+            let area = mem::take(&mut piece_areas[index]);
+            let mut solution = None;
+            if board::is_area_coverable_mask(occupancy | mask, shape, piece_areas) {
+                placements[index] = Some(placement);
+                solution = SolverSingleThreaded::weighted_search(
+                    table,
+                    &[],
+                    placement_weights,
+                    shape,
+                    occupancy | mask,
+                    placements,
+                    piece_areas,
+                );
+                placements[index] = None;
+            }
+            piece_areas[index] = area;
 
-                // Change flag to indicate to undo last move
-                restore_last_state = true;
+            if solution.is_some() {
+                return solution;
             }
+        }
 
-            // If hasn't broken out, has exhausted all possibilities for current loop.
-            // Return to previous solver state or break from loop if finished searching.
-            if restore_last_state {
+        None
+    }
+
+    /// Returns every solution, searching by filling the most constrained empty cell at each step
+    /// (see `PlacementTable::most_constrained_cell`) rather than the top left empty cell. The
+    /// placements of the pieces are found once before the search, and at each step only the
+    /// placements covering the most constrained cell are tried, so each solution is found once.
+    /// The search starts from the pieces currently placed and does not change the state of the
+    /// solver. Panics if the board has more than 64 cells.
+    ///
+    /// In a release build it finds the solutions of 1 January in about a third of the time of
+    /// `find_solution_set` (see the `find_solution_set` benchmarks), and those of every date in
+    /// about a fifth.
+    pub fn find_solutions_most_constrained(&self) -> Vec<Vec<PieceBoardPosition>> {
+        let table = PlacementTable::new(&self.pieces, &self.board);
+        let shape = self.board.get_board_layout().shape().clone();
+        let (current_placements, mut piece_areas) = self.table_search_state();
+        let mut placements: Vec<Option<&PieceBoardPosition>> =
+            current_placements.iter().map(Option::as_ref).collect();
+        let mut solutions: Vec<Vec<PieceBoardPosition>> = Vec::new();
+
+        SolverSingleThreaded::most_constrained_search(
+            &table,
+            &shape,
+            self.board.occupancy(),
+            &mut placements,
+            &mut piece_areas,
+            &mut solutions,
+        );
+
+        solutions
+    }
+
+    /// Returns the placement of each piece placed so far, or `None` for the pieces still to be
+    /// placed, and the area of each piece still to be placed, or 0 for the pieces placed. These
+    /// are the starting state of the searches over a `PlacementTable`.
+    fn table_search_state(&self) -> (Vec<Option<PieceBoardPosition>>, Vec<usize>) {
+        self.pieces
+            .iter()
+            .map(|piece| {
+                if *piece.is_used() {
+                    (Some(piece.get_piece_board_position()), 0)
+                } else {
+                    (None, piece.area())
+                }
+            })
+            .unzip()
+    }
+
+    /// Tries every placement covering the most constrained empty cell which fits and leaves
+    /// regions the remaining pieces could cover, and searches the rest of the board after each
+    /// one, appending any solutions found to `solutions`.
+    ///
+    /// # Arguments
+    /// * `table` - The placements of the pieces.
+    /// * `shape` - The shape of the board.
+    /// * `occupancy` - The occupancy mask of the board with the pieces placed so far.
+    /// * `placements` - The placement of each piece, or `None` if it is still to be placed.
+    /// * `piece_areas` - The area of each piece still to be placed, and 0 for placed pieces.
+    /// * `solutions` - The solutions found so far.
+    fn most_constrained_search<'a>(
+        table: &'a PlacementTable,
+        shape: &Shape,
+        occupancy: u64,
+        placements: &mut [Option<&'a PieceBoardPosition>],
+        piece_areas: &mut [usize],
+        solutions: &mut Vec<Vec<PieceBoardPosition>>,
+    ) {
+        let Some(cell) =
+            table.most_constrained_cell(occupancy, |index| placements[index].is_none())
+        else {
+            solutions.push(
+                placements
+                    .iter()
+                    .flatten()
+                    .map(|&placement| placement.clone())
+                    .collect(),
+            );
+            return;
+        };
+
+        let cell_mask = 1 << cell;
+        for index in 0..placements.len() {
+            if placements[index].is_some() {
+                continue;
+            }
+
+            let area = mem::take(&mut piece_areas[index]);
+            for (placement, mask) in table.placements(index) {
+                if mask & cell_mask == 0
+                    || mask & occupancy != 0
+                    || !board::is_area_coverable_mask(occupancy | mask, shape, piece_areas)
+                {
+                    continue;
+                }
+
+                placements[index] = Some(placement);
+                SolverSingleThreaded::most_constrained_search(
+                    table,
+                    shape,
+                    occupancy | mask,
+                    placements,
+                    piece_areas,
+                    solutions,
+                );
+            }
+            placements[index] = None;
+            piece_areas[index] = area;
+        }
+    }
+
+    /// Searches for every solution and appends each one to `out` as a grid of piece ids, without
+    /// allocating per solution. Every board cell `(row, col)` is stored at `row * cols + col` and
+    /// holds the (1 based) index of the piece covering it, or 0 if it is reserved. Unlike
+    /// `find_solution_set`, the solutions are not added to the `solution_set` vector.
+    /// Panics if the board has more than 49 cells.
+    ///
+    /// # Arguments
+    /// * `out` - The vector the solution grids are appended to.
+    pub fn collect_solution_grids_into(&mut self, out: &mut Vec<[u8; 49]>) {
+        let shape = self.board.get_board_layout().shape().clone();
+        assert!(
+            shape.rows * shape.cols <= 49,
+            "A {} x {} board does not fit in a 49 cell grid",
+            shape.rows,
+            shape.cols
+        );
+
+        loop {
+            match self.step() {
+                SearchStep::Solved => {
+                    let mut grid = [0; 49];
+                    for (index, piece) in self.pieces.iter().enumerate() {
+                        if !*piece.is_used() {
+                            continue;
+                        }
+
+                        let (row, col) = piece.get_board_position().unwrap();
+                        let orientation = piece.current_orientation();
+                        for row_piece in 0..orientation.shape().rows {
+                            for col_piece in 0..orientation.shape().cols {
+                                if orientation.get(row_piece, col_piece) == 1 {
+                                    let cell = self
+                                        .board
+                                        .get_board_layout()
+                                        .to_index(row + row_piece, col + col_piece);
+                                    grid[cell] = index as u8 + 1;
+                                }
+                            }
+                        }
+                    }
+                    out.push(grid);
+                }
+                SearchStep::Exhausted => return,
+                SearchStep::Placed | SearchStep::Backtracked => (),
+            }
+        }
+    }
+
+    /// Searches for every solution and writes each one to a file as it is found, so only the
+    /// solution being searched for is held in memory. Returns the number of solutions written.
+    /// Each line of the file is a solution, with a `piece,row,col,orientation` entry for every
+    /// piece separated by `;`. The piece is its index in the solver's pieces and the orientation
+    /// is its index in `PieceModel::unique_orientations`. Unlike `find_solution_set`, the
+    /// solutions are not added to the `solution_set` vector. Use `solutions_from_file` to read
+    /// the solutions back.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the file to be written.
+    pub fn find_solution_set_to_file<P: AsRef<Path>>(&mut self, path: P) -> io::Result<usize> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        let mut solutions = 0;
+
+        loop {
+            match self.step() {
+                SearchStep::Solved => {
+                    let entries: Vec<String> = self
+                        .pieces
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, piece)| *piece.is_used())
+                        .map(|(index, piece)| {
+                            let (row, col) = piece.get_board_position().unwrap();
+                            format!(
+                                "{},{},{},{}",
+                                index,
+                                row,
+                                col,
+                                piece.current_orientation_index()
+                            )
+                        })
+                        .collect();
+                    writeln!(writer, "{}", entries.join(";"))?;
+                    solutions += 1;
+                }
+                SearchStep::Exhausted => break,
+                SearchStep::Placed | SearchStep::Backtracked => (),
+            }
+        }
+
+        writer.flush()?;
+        Ok(solutions)
+    }
+
+    /// Returns an iterator over the solutions in a file written by `find_solution_set_to_file`,
+    /// reading one solution at a time. The file must have been written by a solver with the same
+    /// pieces. A line which cannot be read as a solution is returned as an `InvalidData` error.
+    ///
+    /// # Arguments
+    /// * `path` - The path of the file to be read.
+    pub fn solutions_from_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> io::Result<impl Iterator<Item = io::Result<Vec<PieceBoardPosition>>> + '_> {
+        let reader = BufReader::new(File::open(path)?);
+
+        Ok(reader
+            .lines()
+            .map(|line| line.and_then(|line| self.parse_solution_line(&line))))
+    }
+
+    /// Returns the solution of a line written by `find_solution_set_to_file`.
+    fn parse_solution_line(&self, line: &str) -> io::Result<Vec<PieceBoardPosition>> {
+        let invalid_data = || io::Error::new(io::ErrorKind::InvalidData, line.to_string());
+
+        line.split(';')
+            .map(|entry| {
+                let values = entry
+                    .split(',')
+                    .map(|value| value.parse::<usize>().map_err(|_| invalid_data()))
+                    .collect::<io::Result<Vec<usize>>>()?;
+                let [index, row, col, orientation_index] = values[..] else {
+                    return Err(invalid_data());
+                };
+
+                let mut piece = self.pieces.get(index).ok_or_else(invalid_data)?.clone();
+                piece.reset();
+                while piece.current_orientation_index() != orientation_index {
+                    if *piece.is_exhausted() {
+                        return Err(invalid_data());
+                    }
+                    piece.next_unique_orientation();
+                }
+                piece.set_board_position(Some((row, col)));
+
+                Ok(piece.get_piece_board_position())
+            })
+            .collect()
+    }
+
+    /// Finds the solution set and returns a reference to it, or an error if the board has no
+    /// empty positions to fill.
+    pub fn try_solve(&mut self) -> Result<&Vec<Vec<PieceBoardPosition>>, DfError> {
+        if board::is_board_complete(self.board.get_board_layout()) {
+            return Err(DfError::BoardFull);
+        }
+
+        self.find_solution_set();
+        Ok(&self.solution_set)
+    }
+
+    /// Returns a board sized array for each piece which counts how many valid placements of the
+    /// piece cover each cell. Placements are made on the solver's board as it currently is, which
+    /// before the search begins is the board with only the date reserved.
+    pub fn piece_coverage_map(&self) -> Vec<Array2D> {
+        let shape = self.board.get_board_layout().shape();
+        let min_area = piece::min_piece_area(&self.pieces);
+
+        self.pieces
+            .iter()
+            .map(|piece| {
+                let mut coverage = Array2D::new(shape.clone(), vec![0; shape.rows * shape.cols]);
+                for placement in self.board.valid_placements(piece, min_area) {
+                    for cell in placement.covered_cells() {
+                        coverage.set(cell, coverage.get(cell.0, cell.1) + 1);
+                    }
+                }
+                coverage
+            })
+            .collect()
+    }
+
+    /// Returns the id (index into `get_pieces`) of the piece with the fewest valid placements on
+    /// the solver's board, which before the search begins is the board with only the date
+    /// reserved. If several pieces have the fewest placements, the first is returned.
+    pub fn most_constrained_piece(&self) -> u8 {
+        let min_area = piece::min_piece_area(&self.pieces);
+        self.pieces
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, piece)| self.board.valid_placements(piece, min_area).len())
+            .map(|(index, _)| index as u8)
+            .unwrap()
+    }
+
+    /// Returns the pieces on the board after every step of the search for the first solution.
+    /// The first frame is the board before any piece is placed, and the last frame is the first
+    /// solution (if one exists). The solver it is called on is left untouched.
+    pub fn first_solution_frames(&self) -> Vec<Vec<PieceBoardPosition>> {
+        let mut solver = self.clone();
+        let mut frames = vec![solver.current_placements()];
+
+        loop {
+            let step = solver.step();
+            if step == SearchStep::Exhausted {
+                break;
+            }
+
+            frames.push(solver.current_placements());
+            if step == SearchStep::Solved {
+                break;
+            }
+        }
+
+        frames
+    }
+
+    /// Continues the search from where it was left and returns the next solution found.
+    /// Returns `None` once every combination has been searched.
+    pub(crate) fn next_solution(&mut self) -> Option<Vec<PieceBoardPosition>> {
+        loop {
+            match self.step() {
+                SearchStep::Solved => return Some(self.current_placements()),
+                SearchStep::Exhausted => return None,
+                SearchStep::Placed | SearchStep::Backtracked => (),
+            }
+        }
+    }
+
+    /// Performs a single step of the search. Either the next valid piece is placed at the next
+    /// available board position, or the last placed piece is removed if no piece can be placed
+    /// or the board is complete.
+    fn step(&mut self) -> SearchStep {
+        if !board::is_board_complete(self.board.get_board_layout()) && self.place_next_piece() {
+            if board::is_board_complete(self.board.get_board_layout()) {
+                return SearchStep::Solved;
+            }
+            return SearchStep::Placed;
+        }
+
+        // Has exhausted all possibilities for current board.
+        // Return to previous solver state or finish if completed search.
+        if self.backtrack() {
+            SearchStep::Backtracked
+        } else {
+            SearchStep::Exhausted
+        }
+    }
+
+    /// Places the next eligible piece at the next available board position.
+    /// Returns false if no piece can be placed.
+    fn place_next_piece(&mut self) -> bool {
+        // Get next available board position
+        let Ok(board_position) = board::next_board_position(self.board.get_board_layout()) else {
+            return false;
+        };
+
+        // Get next eligible piece to be placed
+        for index in self.start_index..self.pieces.len() {
+            if *self.pieces[index].is_used() {
+                continue;
+            }
+
+            // Leave the piece out of the areas of the pieces left to place while it is tried
+            let area = mem::take(&mut self.remaining_areas[index]);
+            let piece = &mut self.pieces[index];
+
+            while !piece.is_exhausted() {
+                // Prune placements leaving a region the remaining pieces cannot fill
+                let is_valid = if let Some(dead_states) = &mut self.dead_states {
+                    let min_area = board::smallest_area(&self.remaining_areas);
+                    match self.board.check_piece(board_position, piece, min_area) {
+                        PlacementCheck::Valid => self.board.leaves_coverable_regions(
+                            piece,
+                            &self.remaining_areas,
+                            &mut self.placement_scratch,
+                        ),
+                        PlacementCheck::Invalid => false,
+                        PlacementCheck::LeavesHoles(board_layout) => {
+                            dead_states.insert(board_layout);
+                            false
+                        }
+                    }
+                } else if let Some(cache) = &mut self.validity_cache {
+                    cache.is_piece_valid_for_areas(
+                        &self.board,
+                        board_position,
+                        piece,
+                        &self.remaining_areas,
+                        &mut self.placement_scratch,
+                    )
+                } else {
+                    self.board.is_piece_valid_for_areas(
+                        board_position,
+                        piece,
+                        &self.remaining_areas,
+                        &mut self.placement_scratch,
+                    )
+                };
+
+                if is_valid {
+                    // Save current board and piece state
+                    self.board.generate_memento();
+                    let piece_state = piece.snapshot();
+
+                    // Update board state, undoing the saved state if the piece does not fit
+                    if self.board.add_piece_to_board(piece).is_ok() {
+                        // Set flag to indicate piece is used
+                        piece.set_used(true);
+
+                        // Save current state of solver
+                        self.solver_history.push((index, piece_state));
+
+                        // Reset start_index
+                        self.start_index = 0;
+
+                        return true;
+                    }
+                    self.board.restore_from_memento();
+                }
+
+                piece.next_unique_orientation();
+            }
+
+            // Orientations Exhausted - reset piece
+            piece.reset();
+            self.remaining_areas[index] = area;
+        }
+
+        false
+    }
+
+    /// Removes the last placed piece and moves it onto its next unique orientation.
+    /// Returns false if there is no piece to remove (i.e. the search is complete).
+    fn backtrack(&mut self) -> bool {
+        let depth = self.solver_history.len();
+        match self.solver_history.pop() {
+            Some((index, piece_state)) => {
+                self.backtrack_depths[depth.min(8)] += 1;
+                self.start_index = index;
+
+                // return to previous board position and piece state
+                self.board.restore_from_memento();
+                self.pieces[index].restore(piece_state);
+                self.remaining_areas[index] = self.pieces[index].area();
+
+                // Get next unique orientation of piece
+                self.pieces[index].next_unique_orientation();
+
+                true
+            }
+            // Completed Search, ready for a new search
+            None => {
+                self.start_index = 0;
+                false
+            }
+        }
+    }
+
+    /// Returns how many times the search has backtracked with each number of pieces placed,
+    /// indexed by the number of pieces on the board when the last piece was removed. Many
+    /// shallow backtracks show branches are pruned early, while deep ones show wasted work.
+    /// Boards needing more than 8 pieces count their deeper backtracks at index 8.
+    pub fn backtrack_depth_histogram(&self) -> [usize; 9] {
+        self.backtrack_depths
+    }
+
+    /// Returns the board positions of the pieces currently placed on the board. Before the search
+    /// begins or once it is complete, no pieces are placed.
+    pub fn current_placements(&self) -> Vec<PieceBoardPosition> {
+        self.pieces
+            .iter()
+            .filter(|piece| *piece.is_used())
+            .map(|piece| piece.get_piece_board_position())
+            .collect()
+    }
+
+    pub fn remove_duplicates(&mut self) {
+        self.solution_set.sort();
+        self.solution_set.dedup();
+    }
+
+    /// Returns the unique solutions, removing duplicates as the search runs rather than after
+    /// collecting every solution. A solution is only kept if the canonical fingerprint of its
+    /// solution grid has not been seen before, so a solution which is a rotation or reflection
+    /// of an earlier one is also dropped. The `solution_set` field is left untouched.
+    pub fn find_unique_solutions(&mut self) -> Vec<Vec<PieceBoardPosition>> {
+        let shape = self.board.get_board_layout().shape().clone();
+        let mut seen: HashSet<u64> = HashSet::new();
+        let mut unique_solutions: Vec<Vec<PieceBoardPosition>> = Vec::new();
+
+        while let Some(solution) = self.next_solution() {
+            let grid = board::solution_grid(&shape, &solution);
+            if seen.insert(board::canonical_fingerprint(&grid)) {
+                unique_solutions.push(solution);
+            }
+        }
+
+        unique_solutions
+    }
+
+    /// Returns how often each piece covers each board cell over every solution, searching for the
+    /// solution set first if it is empty. The counts of cell `(row, col)` are at
+    /// `row * cols + col`, indexed by the piece's index in the solver's pieces. Reserved cells
+    /// are never covered, so their counts are always 0.
+    /// Panics if the board has more than 49 cells or there are more than 8 pieces.
+    pub fn cell_piece_frequency(&mut self) -> [[u16; 8]; 49] {
+        let shape = self.board.get_board_layout().shape().clone();
+        assert!(shape.rows * shape.cols <= 49 && self.pieces.len() <= 8);
+
+        if self.solution_set.is_empty() {
+            self.find_solution_set();
+        }
+
+        let mut frequency = [[0; 8]; 49];
+        for solution in &self.solution_set {
+            for placement in solution {
+                let piece_index = self
+                    .pieces
+                    .iter()
+                    .position(|piece| piece.get_name() == placement.get_name())
+                    .unwrap();
+                for (row, col) in placement.covered_cells() {
+                    frequency[self.board.get_board_layout().to_index(row, col)][piece_index] += 1;
+                }
+            }
+        }
+
+        frequency
+    }
+
+    /// Returns the placement (piece, orientation and board position) found in the most solutions
+    /// and the number of solutions it is found in, searching for the solution set first if it is
+    /// empty. Ties go to the smallest placement.
+    ///
+    /// # Panics!
+    /// Will panic if the board has no solution.
+    pub fn most_common_placement(&mut self) -> (PieceBoardPosition, usize) {
+        if self.solution_set.is_empty() {
+            self.find_solution_set();
+        }
+
+        let mut frequency: HashMap<&PieceBoardPosition, usize> = HashMap::new();
+        for placement in self.solution_set.iter().flatten() {
+            *frequency.entry(placement).or_insert(0) += 1;
+        }
+
+        frequency
+            .into_iter()
+            .max_by(|(placement_a, count_a), (placement_b, count_b)| {
+                count_a.cmp(count_b).then(placement_b.cmp(placement_a))
+            })
+            .map(|(placement, count)| (placement.clone(), count))
+            .expect("No solution was found")
+    }
+
+    /// Returns the solutions in the solution set where the two pieces share an edge, i.e. a cell
+    /// of one piece is directly above, below, left or right of a cell of the other.
+    ///
+    /// # Arguments
+    /// * `id_a` - The index of the first piece in the solver's pieces.
+    /// * `id_b` - The index of the second piece in the solver's pieces.
+    ///
+    /// # Panics!
+    /// Will panic if either id is not the index of a piece.
+    pub fn solutions_with_adjacent_pieces(
+        &self,
+        id_a: u8,
+        id_b: u8,
+    ) -> Vec<&Vec<PieceBoardPosition>> {
+        let name_a = self.pieces[id_a as usize].get_name();
+        let name_b = self.pieces[id_b as usize].get_name();
+        let covered_cells = |solution: &[PieceBoardPosition], name: &str| -> Vec<(usize, usize)> {
+            solution
+                .iter()
+                .filter(|placement| placement.get_name() == name)
+                .flat_map(|placement| placement.covered_cells())
+                .collect()
+        };
+
+        self.solution_set
+            .iter()
+            .filter(|solution| {
+                let cells_b = covered_cells(solution, name_b);
+                covered_cells(solution, name_a).iter().any(|&(row, col)| {
+                    cells_b
+                        .iter()
+                        .any(|&(row_b, col_b)| row.abs_diff(row_b) + col.abs_diff(col_b) == 1)
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the solution set as CSV, with a header row followed by a row for every piece of
+    /// every solution: `solution_index,piece_name,row,col,cell_count`. The row and column are the
+    /// piece's board position, and piece names containing commas or quotes are quoted.
+    pub fn solutions_to_csv(&self) -> String {
+        let mut csv = String::from("solution_index,piece_name,row,col,cell_count\n");
+
+        for (index, solution) in self.solution_set.iter().enumerate() {
+            for piece in solution {
+                let (row, col) = piece.get_board_position();
+                csv.push_str(&format!(
+                    "{},{},{},{},{}\n",
+                    index,
+                    escape_csv_field(piece.get_name()),
+                    row,
+                    col,
+                    piece.covered_cells().len()
+                ));
+            }
+        }
+
+        csv
+    }
+
+    /// Sorts the unique solutions into the order used by puzzle booklets and returns them. The
+    /// solution set is found first if it is empty.
+    ///
+    /// The first piece of the solver is the reference piece. Solutions are ordered by:
+    /// 1. The board position (row, then column) of the reference piece.
+    /// 2. The orientation of the reference piece (compared as an `Array2D`).
+    /// 3. The fingerprint of the solution grid, which orders the placements of the other pieces.
+    pub fn find_solutions_ordered_booklet(&mut self) -> &Vec<Vec<PieceBoardPosition>> {
+        if self.solution_set.is_empty() {
+            self.find_solution_set();
+        }
+        self.remove_duplicates();
+
+        let shape = self.board.get_board_layout().shape().clone();
+        self.solution_set.sort_by_cached_key(|solution| {
+            let reference_piece = &solution[0];
+            (
+                reference_piece.get_board_position(),
+                reference_piece.get_orienation(),
+                board::fingerprint(&board::solution_grid(&shape, solution)),
+            )
+        });
+
+        &self.solution_set
+    }
+
+    /// Returns the number of distinct ways the board is tiled by the solutions, ignoring which
+    /// piece is where. Solutions only differ in this count if the boundaries between their pieces
+    /// differ, so swapping two identical pieces does not give a new tiling. The solution set is
+    /// found first if it is empty.
+    pub fn count_distinct_tilings_ignoring_piece_identity(&mut self) -> usize {
+        if self.solution_set.is_empty() {
+            self.find_solution_set();
+        }
+        self.remove_duplicates();
+
+        let shape = self.board.get_board_layout().shape();
+        self.solution_set
+            .iter()
+            .map(|solution| board::fingerprint(&board::tiling_grid(shape, solution)))
+            .collect::<HashSet<u64>>()
+            .len()
+    }
+
+    /// Returns the number of unique solutions in each symmetry class, keyed by the canonical
+    /// fingerprint of the class.
+    ///
+    /// Solutions are in the same class if one is a rotation or reflection of the other. The
+    /// solution set is found first if it is empty. On the (asymmetric) Dragon Fjord board every
+    /// class has a single member.
+    pub fn solutions_by_symmetry_class(&mut self) -> HashMap<u64, usize> {
+        if self.solution_set.is_empty() {
+            self.find_solution_set();
+        }
+        self.remove_duplicates();
+
+        let shape = self.board.get_board_layout().shape();
+        let mut classes: HashMap<u64, usize> = HashMap::new();
+        for solution in &self.solution_set {
+            let grid = board::solution_grid(shape, solution);
+            *classes
+                .entry(board::canonical_fingerprint(&grid))
+                .or_insert(0) += 1;
+        }
+
+        classes
+    }
+}
+
+/// Returns the area of the smallest unused piece other than the piece being placed, or 0 if it is
+/// the last piece. An empty region left by the placement with fewer cells cannot be filled.
+///
+/// # Arguments
+/// * `pieces` - The puzzle pieces of the search.
+/// * `placing` - The index of the piece being placed.
+fn min_remaining_area(pieces: &[PieceModel], placing: usize) -> usize {
+    pieces
+        .iter()
+        .enumerate()
+        .filter(|&(index, piece)| index != placing && !*piece.is_used())
+        .map(|(_, piece)| piece.area())
+        .min()
+        .unwrap_or(0)
+}
+
+/// Returns the field quoted if it contains a comma, quote or new line, with any quotes doubled.
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// A solver which searches for every solution on several threads. The search is split by the
+/// piece placed first, with one worker thread per piece, and the solutions found by the workers
+/// are collected into a single solution set.
+pub struct SolverMultiThreaded {
+    pieces: [PieceModel; 8],
+    board: BoardModel,
+    solution_set: Vec<Vec<PieceBoardPosition>>,
+}
+
+impl SolverMultiThreaded {
+    pub fn new(day: usize, month: usize) -> SolverMultiThreaded {
+        SolverMultiThreaded {
+            pieces: piece::create_piece_models(),
+            board: BoardModel::new(day, month),
+            solution_set: Vec::new(),
+        }
+    }
+
+    /// Creates worker threads to find solutions for a smaller subset of pieces
+    pub fn find_solution_set(&mut self) {
+        for solution in self.spawn_workers() {
+            self.solution_set.push(solution)
+        }
+    }
+
+    /// Finds the solution set like `find_solution_set`, but skips a solution as it is received if
+    /// an identical solution has already been found, so `remove_duplicates` is not needed
+    /// afterwards. Solutions are compared by the fingerprint of their solution grid, which holds
+    /// the index of the piece covering each cell.
+    pub fn find_unique_solution_set(&mut self) {
+        let shape = self.board.get_board_layout().shape().clone();
+        let mut seen: HashSet<u64> = HashSet::new();
+
+        for solution in self.spawn_workers() {
+            if seen.insert(board::fingerprint(&board::solution_grid(&shape, &solution))) {
+                self.solution_set.push(solution)
+            }
+        }
+    }
+
+    /// Starts a worker thread for each piece placed first and returns the receiver the workers
+    /// send their solutions to. Iterating over the receiver ends once every worker has finished.
+    fn spawn_workers(&self) -> Receiver<Vec<PieceBoardPosition>> {
+        let (sender, receiver): (
+            Sender<Vec<PieceBoardPosition>>,
+            Receiver<Vec<PieceBoardPosition>>,
+        ) = mpsc::channel();
+
+        for index in 0..self.pieces.len() {
+            let board_thread = self.board.clone();
+            let sender_thread = sender.clone();
+
+            thread::spawn(move || {
+                let mut worker = Worker::create_new_worker(board_thread, sender_thread);
+                worker.find_solution_set(index, index + 1);
+            });
+        }
+
+        // It is possible to iterate over the receiver. The receiver will block
+        // when the iterator asks for the next value. When the channel is closed,
+        // the iterator will return None and end.
+        // Issue if the original sender is cloned, as the original sender is never dropped.
+        // Need to drop the original sender to prevent an infinite loop.
+        // https://nickymeuleman.netlify.app/blog/multithreading-rust
+        // drop the original sender, else the channel will remain open, causing the receiver to infinitely wait
+        mem::drop(sender);
+
+        receiver
+    }
+
+    /// Returns an immutable reference to pieces field.
+    pub fn get_pieces(&self) -> &[PieceModel; 8] {
+        &self.pieces
+    }
+
+    /// Returns an immutable reference to the solution_set field.
+    pub fn get_solution_set(&self) -> &Vec<Vec<PieceBoardPosition>> {
+        &self.solution_set
+    }
+
+    pub fn remove_duplicates(&mut self) {
+        self.solution_set.sort();
+        self.solution_set.dedup();
+    }
+}
+
+struct Worker {
+    pieces: [PieceModel; 8],
+    board: BoardModel,
+    sender: Sender<Vec<PieceBoardPosition>>,
+}
+
+impl Worker {
+    /// Create a brand new worker
+    pub fn create_new_worker(board: BoardModel, sender: Sender<Vec<PieceBoardPosition>>) -> Worker {
+        Worker {
+            pieces: piece::create_piece_models(),
+            board,
+            sender,
+        }
+    }
+
+    /// Returns a solution set for solver.
+    ///
+    /// Iterates through all possible combinations and appends valid
+    ///  solutions to the `solution_set` vector.
+    pub fn find_solution_set(&mut self, mut start_index: usize, mut end_index: usize) {
+        // Create memento to handle state
+        let mut solver_history: Vec<(usize, usize)> = Vec::new();
+
+        loop {
+            // Flag to determine when to go back to previous state
+            let mut restore_last_state = true;
+
+            // Get next available board position. The search is complete if the board is full.
+            let Ok(board_position) = board::next_board_position(self.board.get_board_layout())
+            else {
+                break;
+            };
+
+            // Get next eligible piece to be placed
+            'piece_loop: for index in start_index..end_index {
+                let min_area = min_remaining_area(&self.pieces, index);
+                let piece = &mut self.pieces[index];
+
+                if !piece.is_used() {
+                    while !piece.is_exhausted() {
+                        if self.board.is_piece_valid(board_position, piece, min_area) {
+                            // Save current board state
+                            self.board.generate_memento();
+
+                            // Update board state, undoing the saved state if the piece does not fit
+                            if self.board.add_piece_to_board(piece).is_ok() {
+                                // Set flag to indicate piece is used
+                                piece.set_used(true);
+
+                                // Save current state of solver
+                                solver_history.push((index, end_index));
+
+                                // Set loop flag
+                                restore_last_state = false;
+
+                                break 'piece_loop;
+                            }
+                            self.board.restore_from_memento();
+                        }
+
+                        piece.next_unique_orientation();
+                    }
+
+                    // Orientations Exhausted - reset piece
+                    piece.reset();
+                }
+            }
+
+            // Reset start_index & end_index if a piece was placed
+            if !restore_last_state {
+                start_index = 0;
+                end_index = self.pieces.len();
+            }
+
+            // Check if board is complete. Add solution to solution set if it is complete.
+            if board::is_board_complete(self.board.get_board_layout()) {
+                // A solutions has been found. Record solution
+                let mut solution: Vec<PieceBoardPosition> = Vec::new();
+                for piece in &self.pieces {
+                    solution.push(piece.get_piece_board_position());
+                }
+
+                // Send solution to receiver to be appended to solution set
+                self.sender.send(solution).unwrap();
+
+                // Change flag to indicate to undo last move
+                restore_last_state = true;
+            }
+
+            // If hasn't broken out, has exhausted all possibilities for current loop.
+            // Return to previous solver state or break from loop if finished searching.
+            if restore_last_state {
                 match solver_history.pop() {
                     Some(x) => {
                         (start_index, end_index) = x;
 
-                        // return to previous board position
-                        self.board.restore_from_memento();
+                        // return to previous board position
+                        self.board.restore_from_memento();
+
+                        // Remove flag indicating piece is used
+                        self.pieces[start_index].set_used(false);
+                        self.pieces[start_index].set_board_position(None);
+
+                        // Get next unique orientation of piece
+                        self.pieces[start_index].next_unique_orientation();
+                    }
+                    // Completed Search, break from outer loop
+                    None => break,
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array2D;
+
+    #[test]
+    fn symmetry_classes_on_dragon_fjord_board() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(6, 10);
+
+        // Act
+        let classes = solver.solutions_by_symmetry_class();
+
+        // Assert
+        assert_eq!(solver.get_solution_set().len(), classes.len());
+        assert!(classes.values().all(|&members| members == 1));
+    }
 
-                        // Remove flag indicating piece is used
-                        self.pieces[start_index].set_used(false);
-                        self.pieces[start_index].set_board_position(None);
+    #[test]
+    fn unique_solutions_match_remove_duplicates() {
+        for (day, month) in [(6, 10), (4, 11), (15, 3)] {
+            // Arrange
+            let mut solver = SolverSingleThreaded::new(day, month);
+            let mut streaming_solver = SolverSingleThreaded::new(day, month);
 
-                        // Get next unique orientation of piece
-                        self.pieces[start_index].next_unique_orientation();
+            // Act
+            solver.find_solution_set();
+            solver.remove_duplicates();
+            let unique_solutions = streaming_solver.find_unique_solutions();
+
+            // Assert
+            assert_eq!(
+                solver.get_solution_set().len(),
+                unique_solutions.len(),
+                "{day}/{month}"
+            );
+            assert!(streaming_solver.get_solution_set().is_empty());
+        }
+    }
+
+    #[test]
+    fn multi_threaded_unique_solution_set_matches_remove_duplicates() {
+        // Arrange
+        let mut solver = SolverMultiThreaded::new(4, 11);
+        let mut unique_solver = SolverMultiThreaded::new(4, 11);
+
+        // Act
+        solver.find_solution_set();
+        solver.remove_duplicates();
+        unique_solver.find_unique_solution_set();
+
+        // Assert
+        // Solutions arrive in the order the workers find them, so sort before comparing
+        let mut unique_solutions = unique_solver.get_solution_set().clone();
+        unique_solutions.sort();
+        assert_eq!(solver.get_solution_set(), &unique_solutions);
+    }
+
+    #[test]
+    fn most_constrained_search_matches_find_solution_set() {
+        for (day, month) in [(6, 10), (4, 11)] {
+            // Arrange
+            let mut solver = SolverSingleThreaded::new(day, month);
+
+            // Act
+            let mut solutions = solver.find_solutions_most_constrained();
+            solver.find_solution_set();
+            solver.remove_duplicates();
+
+            // Assert
+            solutions.sort();
+            assert_eq!(solver.get_solution_set(), &solutions, "{day}/{month}");
+        }
+    }
+
+    #[test]
+    fn validity_cache_gives_same_solutions() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(6, 10);
+        let mut cached_solver = SolverSingleThreaded::new(6, 10).with_validity_cache();
+
+        // Act
+        solver.find_solution_set();
+        cached_solver.find_solution_set();
+
+        // Assert
+        assert_eq!(solver.get_solution_set(), cached_solver.get_solution_set());
+        let cache = cached_solver.get_validity_cache().unwrap();
+        assert!(!cache.is_empty());
+        assert!(cache.hit_rate() > 0.0);
+        assert!(solver.get_validity_cache().is_none());
+    }
+
+    #[test]
+    fn current_placements_after_two_pieces() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(6, 10);
+        assert!(solver.current_placements().is_empty());
+
+        // Act
+        assert_eq!(SearchStep::Placed, solver.step());
+        assert_eq!(SearchStep::Placed, solver.step());
+        let placements = solver.current_placements();
+
+        // Assert
+        let used_pieces: Vec<PieceBoardPosition> = solver
+            .get_pieces()
+            .iter()
+            .filter(|piece| *piece.is_used())
+            .map(|piece| piece.get_piece_board_position())
+            .collect();
+        assert_eq!(2, placements.len());
+        assert_eq!(used_pieces, placements);
+
+        // Both pieces start in the first row of the board and cover only filled cells.
+        let board_layout = solver.board.get_board_layout();
+        for placement in &placements {
+            assert_eq!(0, placement.get_board_position().0);
+            for (row, col) in placement.covered_cells() {
+                assert_eq!(1, board_layout.get(row, col));
+            }
+        }
+    }
+
+    #[test]
+    fn multi_threaded_matches_single_threaded() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(1, 1);
+        let mut multi_solver = SolverMultiThreaded::new(1, 1);
+
+        // Act
+        solver.find_solution_set();
+        solver.remove_duplicates();
+        multi_solver.find_solution_set();
+        multi_solver.remove_duplicates();
+
+        // Assert
+        assert!(!solver.get_solution_set().is_empty());
+        assert_eq!(solver.get_solution_set(), multi_solver.get_solution_set());
+    }
+
+    #[test]
+    fn solve_auto_agrees_with_explicit_solvers() {
+        // Arrange
+        let (day, month) = (6, 10);
+        let mut solver = SolverSingleThreaded::new(day, month);
+        let first_solution = solver.clone().next_solution();
+        solver.find_solution_set();
+        solver.remove_duplicates();
+        let mut multi_solver = SolverMultiThreaded::new(day, month);
+        multi_solver.find_solution_set();
+        multi_solver.remove_duplicates();
+
+        // Act
+        let first = solve_auto(day, month, SolveMode::First);
+        let count = solve_auto(day, month, SolveMode::Count);
+        let all = solve_auto(day, month, SolveMode::All);
+
+        // Assert
+        assert_eq!(SolveOutcome::First(first_solution), first);
+        assert_eq!(SolveOutcome::Count(solver.get_solution_set().len()), count);
+        assert_eq!(
+            SolveOutcome::All(multi_solver.get_solution_set().clone()),
+            all
+        );
+        assert_eq!(SolveOutcome::All(solver.get_solution_set().clone()), all);
+    }
+
+    #[test]
+    fn solve_across_repeated_variants() {
+        // Arrange
+        let variants = [CalendarVariant::DragonFjord, CalendarVariant::DragonFjord];
+
+        // Act
+        let counts = solve_across_variants(6, 10, None, &variants);
+
+        // Assert
+        assert_eq!(
+            Ok(vec![
+                (CalendarVariant::DragonFjord, 7),
+                (CalendarVariant::DragonFjord, 7)
+            ]),
+            counts
+        );
+    }
+
+    #[test]
+    fn solve_across_weekday_variant_without_weekday() {
+        // Arrange
+        let variants = [CalendarVariant::DragonFjord, CalendarVariant::WeekdayPlus];
+
+        // Act
+        let counts = solve_across_variants(6, 10, None, &variants);
+
+        // Assert
+        assert_eq!(Err(DfError::MissingWeekday), counts);
+    }
+
+    #[test]
+    fn solve_across_weekday_variant() {
+        // Arrange
+        let variants = [CalendarVariant::DragonFjord, CalendarVariant::WeekdayPlus];
+
+        // Act
+        // 6 October 2026 is a Tuesday
+        let counts = solve_across_variants(6, 10, Some(Weekday::Tue), &variants);
+
+        // Assert
+        assert_eq!(
+            Ok(vec![
+                (CalendarVariant::DragonFjord, 7),
+                (CalendarVariant::WeekdayPlus, 0)
+            ]),
+            counts
+        );
+    }
+
+    #[test]
+    fn booklet_order_is_stable() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(6, 10);
+        let mut other_solver = SolverSingleThreaded::new(6, 10);
+
+        // Act
+        let solutions = solver.find_solutions_ordered_booklet().clone();
+        let other_solutions = other_solver.find_solutions_ordered_booklet();
+
+        // Assert
+        assert_eq!(&solutions, other_solutions);
+        assert_eq!(&solutions, solver.find_solutions_ordered_booklet());
+
+        let first_position = solutions[0][0].get_board_position();
+        assert!(solutions
+            .iter()
+            .all(|solution| solution[0].get_board_position() >= first_position));
+        assert_eq!("2x3 No Hole", solutions[0][0].get_name());
+    }
+
+    #[test]
+    #[rustfmt::skip::macros(array2D)]
+    fn solutions_to_csv_round_trip() {
+        // Arrange
+        let board = BoardModel::from_layout(array2D!(
+            [0, 0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 0]
+        ));
+        let pieces = vec![
+            PieceModel::new(
+                "Block, left".to_string(),
+                array2D!([1, 1, 1], [1, 1, 1]),
+                1,
+                false,
+            ),
+            PieceModel::new(
+                "Block \"B\"".to_string(),
+                array2D!([1, 1, 1], [1, 1, 1]),
+                1,
+                false,
+            ),
+        ];
+        let mut solver = SolverSingleThreaded::with_board(board, pieces);
+        solver.find_solution_set();
+
+        // Act
+        let csv = solver.solutions_to_csv();
+
+        // Assert
+        let mut lines = csv.lines();
+        assert_eq!(
+            Some("solution_index,piece_name,row,col,cell_count"),
+            lines.next()
+        );
+
+        let mut rows: Vec<Vec<String>> = Vec::new();
+        for line in lines {
+            // Split on commas outside of quotes
+            let mut fields: Vec<String> = vec![String::new()];
+            let mut in_quotes = false;
+            let mut chars = line.chars().peekable();
+            while let Some(c) = chars.next() {
+                match c {
+                    '"' if in_quotes && chars.peek() == Some(&'"') => {
+                        fields.last_mut().unwrap().push('"');
+                        chars.next();
                     }
-                    // Completed Search, break from outer loop
-                    None => break,
+                    '"' => in_quotes = !in_quotes,
+                    ',' if !in_quotes => fields.push(String::new()),
+                    _ => fields.last_mut().unwrap().push(c),
                 }
             }
+            rows.push(fields);
+        }
+
+        let piece_count: usize = solver.get_solution_set().iter().map(Vec::len).sum();
+        assert_eq!(piece_count, rows.len());
+        assert!(rows.iter().all(|row| row.len() == 5 && row[4] == "6"));
+        assert_eq!("Block, left", rows[0][1]);
+        assert_eq!("Block \"B\"", rows[1][1]);
+    }
+
+    #[test]
+    fn most_constrained_piece_has_fewest_placements() {
+        // Arrange
+        let solver = SolverSingleThreaded::new(21, 5);
+        let min_area = piece::min_piece_area(solver.get_pieces());
+        let placement_counts: Vec<usize> = solver
+            .get_pieces()
+            .iter()
+            .map(|piece| solver.board.valid_placements(piece, min_area).len())
+            .collect();
+
+        // Act
+        let piece_id = solver.most_constrained_piece();
+
+        // Assert
+        assert_eq!(
+            placement_counts.iter().min(),
+            Some(&placement_counts[piece_id as usize])
+        );
+    }
+
+    #[test]
+    fn try_new_invalid_date() {
+        // Arrange & Act
+        let solver = SolverSingleThreaded::try_new(0, 1);
+
+        // Assert
+        assert!(matches!(
+            solver,
+            Err(DfError::InvalidDate { day: 0, month: 1 })
+        ));
+    }
+
+    #[test]
+    fn try_solve_valid_date() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::try_new(6, 10).unwrap();
+
+        // Act
+        let solution_set = solver.try_solve().unwrap();
+
+        // Assert
+        assert_eq!(7, solution_set.len());
+    }
+
+    #[test]
+    #[rustfmt::skip::macros(array2D)]
+    fn try_solve_full_board() {
+        // Arrange
+        let board = BoardModel::from_layout(array2D!(
+            [1, 1],
+            [1, 1]
+        ));
+        let mut solver = SolverSingleThreaded::with_board(board, Vec::new());
+
+        // Act
+        let result = solver.try_solve();
+
+        // Assert
+        assert_eq!(Err(DfError::BoardFull), result);
+    }
+
+    #[test]
+    fn first_solution_frames_end_at_first_solution() {
+        // Arrange
+        let solver = SolverSingleThreaded::new(6, 10);
+        let mut first_solution = solver.clone();
+        first_solution.find_solution_set();
+
+        // Act
+        let frames = solver.first_solution_frames();
+
+        // Assert
+        assert!(frames[0].is_empty());
+        assert_eq!(first_solution.get_solution_set().first(), frames.last());
+        assert!(solver.get_solution_set().is_empty());
+    }
+
+    #[test]
+    fn piece_coverage_map_of_empty_board() {
+        // Arrange
+        let solver = SolverSingleThreaded::new(21, 5);
+        let board_layout = BoardModel::new(21, 5).get_board_layout().clone();
+
+        // Act
+        let coverage_map = solver.piece_coverage_map();
+
+        // Assert
+        assert_eq!(8, coverage_map.len());
+        for (index, &cell) in board_layout.data().iter().enumerate() {
+            let counts: Vec<u8> = coverage_map
+                .iter()
+                .map(|coverage| coverage.data()[index])
+                .collect();
+
+            if cell == 1 {
+                assert!(counts.iter().all(|&count| count == 0));
+            } else {
+                assert!(counts.iter().any(|&count| count > 0));
+            }
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip::macros(array2D)]
+    fn symmetry_classes_on_symmetric_board() {
+        // Arrange
+        let board = BoardModel::from_layout(array2D!(
+            [0, 0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 0]
+        ));
+        let pieces = vec![
+            PieceModel::new("A".to_string(), array2D!([1, 1, 1], [1, 1, 1]), 1, false),
+            PieceModel::new("B".to_string(), array2D!([1, 1, 1], [1, 1, 1]), 1, false),
+        ];
+        let mut solver = SolverSingleThreaded::with_board(board, pieces);
+
+        // Act
+        let classes = solver.solutions_by_symmetry_class();
+
+        // Assert
+        assert_eq!(2, solver.get_solution_set().len());
+        assert_eq!(1, classes.len());
+        assert_eq!(Some(&2), classes.values().next());
+    }
+
+    #[test]
+    fn distinct_tilings_on_dragon_fjord_board() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(6, 10);
+
+        // Act
+        let tilings = solver.count_distinct_tilings_ignoring_piece_identity();
+
+        // Assert
+        assert_eq!(solver.get_solution_set().len(), tilings);
+    }
+
+    #[test]
+    #[rustfmt::skip::macros(array2D)]
+    fn distinct_tilings_with_identical_pieces() {
+        // Arrange
+        let board = BoardModel::from_layout(array2D!(
+            [0, 0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0, 0]
+        ));
+        let pieces = vec![
+            PieceModel::new("A".to_string(), array2D!([1, 1, 1], [1, 1, 1]), 1, false),
+            PieceModel::new("B".to_string(), array2D!([1, 1, 1], [1, 1, 1]), 1, false),
+        ];
+        let mut solver = SolverSingleThreaded::with_board(board, pieces);
+
+        // Act
+        let tilings = solver.count_distinct_tilings_ignoring_piece_identity();
+
+        // Assert
+        assert_eq!(2, solver.get_solution_set().len());
+        assert_eq!(1, tilings);
+    }
+
+    #[test]
+    #[rustfmt::skip::macros(array2D)]
+    fn dead_states_on_simple_board() {
+        // Arrange
+        let board = BoardModel::from_layout(array2D!(
+            [0, 0, 0, 0, 0],
+            [0, 0, 0, 0, 0]
+        ));
+        let pieces = vec![
+            PieceModel::new(
+                "L".to_string(),
+                array2D!([1, 1, 1, 1], [1, 0, 0, 0]),
+                0,
+                false,
+            ),
+            PieceModel::new(
+                "J".to_string(),
+                array2D!([0, 0, 0, 1], [1, 1, 1, 1]),
+                0,
+                false,
+            ),
+        ];
+        let mut solver = SolverSingleThreaded::with_board(board, pieces).with_dead_states();
+        // Placing J untranslated at the top left corner leaves a hole of 3 cells.
+        let expected_result = vec![array2D!(
+            [0, 0, 0, 1, 0],
+            [1, 1, 1, 1, 0]
+        )];
+
+        // Act
+        solver.find_solution_set();
+
+        // Assert
+        assert_eq!(1, solver.get_solution_set().len());
+        assert_eq!(expected_result, solver.dead_states());
+    }
+
+    #[test]
+    fn dead_states_not_collected_by_default() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(6, 10);
+        let mut tracking_solver = SolverSingleThreaded::new(6, 10).with_dead_states();
+
+        // Act
+        solver.find_solution_set();
+        tracking_solver.find_solution_set();
+
+        // Assert
+        assert!(solver.dead_states().is_empty());
+        assert!(!tracking_solver.dead_states().is_empty());
+        assert_eq!(
+            solver.get_solution_set(),
+            tracking_solver.get_solution_set()
+        );
+    }
+
+    #[test]
+    fn find_solution_set_within_resumes() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(6, 10);
+        let mut expected_result = solver.clone();
+        expected_result.find_solution_set();
+
+        // Act
+        let completed_early = solver.find_solution_set_within(Duration::ZERO);
+        while !solver.find_solution_set_within(Duration::from_millis(10)) {}
+
+        // Assert
+        assert!(!completed_early);
+        assert_eq!(
+            expected_result.get_solution_set(),
+            solver.get_solution_set()
+        );
+    }
+
+    #[test]
+    fn date_requires_flips() {
+        // Arrange
+        let mut no_flips = SolverSingleThreaded::new(6, 10).without_flips();
+        let mut full = SolverSingleThreaded::new(6, 10);
+
+        // Act
+        no_flips.find_solution_set();
+        full.find_solution_set();
+
+        // Assert
+        assert!(no_flips.get_solution_set().is_empty());
+        assert!(!full.get_solution_set().is_empty());
+    }
+
+    #[test]
+    fn solution_reports_flipped_pieces() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(6, 10);
+        let unflipped: Vec<(String, Vec<Array2D>)> = solver
+            .get_pieces()
+            .iter()
+            .map(|piece| {
+                let mut piece = piece.clone();
+                piece.set_flippable(false);
+                (piece.get_name().to_string(), piece.unique_orientations())
+            })
+            .collect();
+
+        // Act
+        solver.find_solution_set();
+
+        // Assert
+        // 6 October cannot be solved without flips, so every solution has a flipped piece
+        for solution in solver.get_solution_set() {
+            assert!(solution.iter().any(|placement| placement.is_flipped()));
+
+            for placement in solution {
+                let (_, orientations) = unflipped
+                    .iter()
+                    .find(|(name, _)| name == placement.get_name())
+                    .unwrap();
+                assert_eq!(
+                    !orientations.contains(&placement.get_orienation()),
+                    placement.is_flipped()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn backtrack_depth_histogram_counts_backtracks() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(6, 10);
+        let mut backtracks = 0;
+
+        // Act
+        loop {
+            match solver.step() {
+                SearchStep::Backtracked => backtracks += 1,
+                SearchStep::Exhausted => break,
+                SearchStep::Placed | SearchStep::Solved => (),
+            }
+        }
+        let histogram = solver.backtrack_depth_histogram();
+
+        // Assert
+        assert_eq!(backtracks, histogram.iter().sum::<usize>());
+        // A backtrack always removes a placed piece
+        assert_eq!(0, histogram[0]);
+        // Every solution is followed by a backtrack from a full board
+        assert!(histogram[8] >= 7);
+    }
+
+    #[test]
+    fn find_first_solution_covers_every_empty_cell() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(6, 10);
+        let mut board_layout = BoardModel::new(6, 10).get_board_layout().clone();
+
+        // Act
+        let solution = solver.find_first_solution();
+
+        // Assert
+        for placement in solution.unwrap() {
+            for cell in placement.covered_cells() {
+                assert_eq!(0, board_layout[cell], "{cell:?} is covered twice");
+                board_layout[cell] = 1;
+            }
+        }
+        assert!(board::is_board_complete(&board_layout));
+        assert!(solver.get_solution_set().is_empty());
+    }
+
+    #[test]
+    fn solutions_iterator_is_lazy() {
+        // Arrange
+        let solver = SolverSingleThreaded::new(6, 10);
+
+        // Act
+        let mut solutions = solver.solutions();
+        let first = solutions.next();
+
+        // Assert
+        assert!(first.is_some());
+        assert_eq!(6, solutions.count());
+    }
+
+    #[test]
+    fn solutions_iterator_matches_find_solution_set() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(4, 11);
+        let iterator_solver = solver.clone();
+
+        // Act
+        solver.find_solution_set();
+        let solutions: Vec<Vec<PieceBoardPosition>> = iterator_solver.solutions().collect();
+
+        // Assert
+        assert_eq!(solver.get_solution_set(), &solutions);
+    }
+
+    #[test]
+    fn count_solutions_matches_find_solution_set() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(6, 10);
+        let mut counting_solver = solver.clone();
+
+        // Act
+        solver.find_solution_set();
+        let count = counting_solver.count_solutions();
+
+        // Assert
+        assert_eq!(solver.get_solution_set().len(), count);
+        assert!(counting_solver.get_solution_set().is_empty());
+    }
+
+    #[test]
+    fn progress_reports_every_solution() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(6, 10);
+        let mut solutions_found = 0;
+        let mut pieces_placed = 0;
+        let mut backtracks = 0;
+
+        // Act
+        solver.find_solution_set_with_progress(|event| match event {
+            SolverEvent::PiecePlaced { .. } => pieces_placed += 1,
+            SolverEvent::Backtracked { .. } => backtracks += 1,
+            SolverEvent::SolutionFound { count } => {
+                solutions_found += 1;
+                assert_eq!(solutions_found, count);
+            }
+        });
+
+        // Assert
+        assert_eq!(solver.get_solution_set().len(), solutions_found);
+        // Every placed piece is removed again by the end of the search
+        assert_eq!(pieces_placed, backtracks);
+    }
+
+    #[test]
+    fn cancelled_search_stops_and_resumes() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(1, 1);
+        let mut full_solver = solver.clone();
+        full_solver.find_solution_set();
+        let cancel = AtomicBool::new(false);
+
+        // Act
+        let is_complete = thread::scope(|scope| {
+            scope.spawn(|| {
+                thread::sleep(Duration::from_millis(10));
+                cancel.store(true, Ordering::Relaxed);
+            });
+            solver.find_solution_set_cancellable(&cancel)
+        });
+
+        // Assert
+        assert!(!is_complete);
+        assert!(solver.get_solution_set().len() <= full_solver.get_solution_set().len());
+
+        cancel.store(false, Ordering::Relaxed);
+        assert!(solver.find_solution_set_cancellable(&cancel));
+        assert_eq!(full_solver.get_solution_set(), solver.get_solution_set());
+    }
+
+    #[test]
+    fn cancelled_before_start_finds_nothing() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(6, 10);
+
+        // Act
+        let is_complete = solver.find_solution_set_cancellable(&AtomicBool::new(true));
+
+        // Assert
+        assert!(!is_complete);
+        assert!(solver.get_solution_set().is_empty());
+    }
+
+    #[test]
+    fn pieces_from_spec_solve_like_standard_pieces() {
+        // Arrange
+        let spec: Vec<String> = piece::create_piece_models()
+            .iter()
+            .map(|piece| piece.to_spec_string())
+            .collect();
+        let pieces = PieceModel::from_spec_str(&spec.join("\n")).unwrap();
+        let mut solver = SolverSingleThreaded::new(6, 10);
+        let mut spec_solver = SolverSingleThreaded::with_board(BoardModel::new(6, 10), pieces);
+
+        // Act
+        solver.find_solution_set();
+        spec_solver.find_solution_set();
+
+        // Assert
+        assert_eq!(solver.get_solution_set(), spec_solver.get_solution_set());
+    }
+
+    #[test]
+    fn backtrack_restores_board_from_memento() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(6, 10);
+        let expected_result = BoardModel::new(6, 10);
+
+        // Act
+        let step = solver.step();
+        let placed_layout = solver.board.get_board_layout().clone();
+        let has_backtracked = solver.backtrack();
+
+        // Assert
+        assert_eq!(SearchStep::Placed, step);
+        assert_ne!(expected_result.get_board_layout(), &placed_layout);
+        assert!(has_backtracked);
+        assert_eq!(
+            expected_result.get_board_layout(),
+            solver.board.get_board_layout()
+        );
+        assert_eq!(
+            expected_result.layout_fingerprint(),
+            solver.board.layout_fingerprint()
+        );
+    }
+
+    #[test]
+    #[rustfmt::skip::macros(array2D)]
+    fn symmetry_reduction_halves_mirrored_solutions() {
+        // Arrange
+        // A 2 x 5 board is the same when mirrored, and is tiled by two L pieces
+        let board = BoardModel::from_layout(Array2D::repeat_row(&[0; 5], 2));
+        let pieces = vec![
+            PieceModel::from_shape("L1".to_string(), array2D!([0, 0, 0, 1], [1, 1, 1, 1])),
+            PieceModel::from_shape("L2".to_string(), array2D!([0, 0, 0, 1], [1, 1, 1, 1])),
+        ];
+        let mut solver = SolverSingleThreaded::with_board(board.clone(), pieces.clone());
+        let mut reduced_solver =
+            SolverSingleThreaded::with_board(board, pieces).with_symmetry_reduction(true);
+
+        // Act
+        solver.find_solution_set();
+        reduced_solver.find_solution_set();
+
+        // Assert
+        assert!(!reduced_solver.get_solution_set().is_empty());
+        assert_eq!(
+            solver.get_solution_set().len(),
+            2 * reduced_solver.get_solution_set().len()
+        );
+        let mut expected_result = solver.full_solution_set();
+        let mut full_solutions = reduced_solver.full_solution_set();
+        expected_result.sort();
+        full_solutions.sort();
+        assert_eq!(expected_result, full_solutions);
+    }
+
+    #[test]
+    fn with_piece_subset_solves_smaller_board() {
+        // Arrange
+        // The middle hole, end hole and zig zag pieces cover 15 cells
+        let board = BoardModel::from_layout(Array2D::repeat_row(&[0; 5], 3));
+
+        // Act
+        let mut solver = SolverSingleThreaded::with_piece_subset(board, &[1, 2, 3]).unwrap();
+        let solution = solver.next_solution();
+
+        // Assert
+        let solution = solution.unwrap();
+        assert_eq!(3, solution.len());
+        assert_eq!(
+            15,
+            solution
+                .iter()
+                .map(|placement| placement.covered_cells().len())
+                .sum::<usize>()
+        );
+    }
+
+    #[test]
+    fn pieces_smaller_than_standard_pieces_fill_board() {
+        // Arrange
+        // Each square covers 4 cells, so the 4 cell regions either side of the first square
+        // must not be pruned as holes
+        let board = BoardModel::from_layout(Array2D::repeat_row(&[0; 4], 2));
+        let square = PieceModel::from_shape("Square".to_string(), array2D!([1, 1], [1, 1]));
+        let mut solver = SolverSingleThreaded::with_board(board, vec![square.clone(), square]);
+
+        // Act
+        solver.find_solution_set();
+
+        // Assert
+        assert_eq!(2, solver.get_solution_set().len());
+    }
+
+    #[test]
+    fn with_piece_subset_rejects_board_without_date() {
+        // Arrange
+        let undated = BoardModel::from_layout(
+            Array2D::from_rows(&[
+                [0, 0, 0, 0, 0, 0, 1],
+                [0, 0, 0, 0, 0, 0, 1],
+                [0, 0, 0, 0, 0, 0, 0],
+                [0, 0, 0, 0, 0, 0, 0],
+                [0, 0, 0, 0, 0, 0, 0],
+                [0, 0, 0, 0, 0, 0, 0],
+                [0, 0, 0, 1, 1, 1, 1],
+            ])
+            .unwrap(),
+        );
+
+        // Act
+        let result = SolverSingleThreaded::with_piece_subset(undated, &[0, 1, 2, 3, 4, 5, 6, 7]);
+
+        // Assert
+        assert_eq!(
+            Some(DfError::AreaMismatch {
+                board_cells: 43,
+                piece_cells: 41
+            }),
+            result.err()
+        );
+    }
+
+    #[test]
+    fn with_piece_subset_rejects_unbalanced_pieces() {
+        // Arrange
+        let board = BoardModel::from_layout(Array2D::repeat_row(&[0; 5], 3));
+
+        // Act
+        let area_mismatch = SolverSingleThreaded::with_piece_subset(board.clone(), &[0, 1, 2]);
+        let unknown_piece = SolverSingleThreaded::with_piece_subset(board, &[1, 2, 8]);
+
+        // Assert
+        assert_eq!(
+            Some(DfError::AreaMismatch {
+                board_cells: 15,
+                piece_cells: 16
+            }),
+            area_mismatch.err()
+        );
+        assert_eq!(Some(DfError::UnknownPiece { id: 8 }), unknown_piece.err());
+    }
+
+    #[test]
+    fn solutions_with_adjacent_pieces_share_an_edge() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(4, 11);
+        solver.find_solution_set();
+        let shape = BoardModel::new(4, 11).get_board_layout().shape().clone();
+        let names: Vec<String> = solver
+            .get_pieces()
+            .iter()
+            .map(|piece| piece.get_name().to_string())
+            .collect();
+
+        // Act
+        let adjacent = solver.solutions_with_adjacent_pieces(0, 7);
+
+        // Assert
+        assert!(!adjacent.is_empty());
+        assert!(adjacent.len() < solver.get_solution_set().len());
+        for solution in solver.get_solution_set() {
+            // Label the two pieces on a grid and look for neighbouring cells with both labels
+            let label = |name: &str| {
+                solution
+                    .iter()
+                    .position(|placement| placement.get_name() == name)
+                    .unwrap() as u8
+                    + 1
+            };
+            let (label_a, label_b) = (label(&names[0]), label(&names[7]));
+            let grid = board::solution_grid(&shape, solution);
+            let is_adjacent = (0..shape.rows).any(|row| {
+                (0..shape.cols).any(|col| {
+                    let cell = grid.get(row, col);
+                    let right = (col + 1 < shape.cols).then(|| grid.get(row, col + 1));
+                    let below = (row + 1 < shape.rows).then(|| grid.get(row + 1, col));
+                    [right, below].into_iter().flatten().any(|neighbour| {
+                        (cell, neighbour) == (label_a, label_b)
+                            || (cell, neighbour) == (label_b, label_a)
+                    })
+                })
+            });
+
+            assert_eq!(is_adjacent, adjacent.contains(&solution));
+        }
+    }
+
+    #[test]
+    fn cell_piece_frequency_sums_to_solution_count() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(6, 10);
+        let board_layout = BoardModel::new(6, 10).get_board_layout().clone();
+
+        // Act
+        let frequency = solver.cell_piece_frequency();
+
+        // Assert
+        let solutions = solver.get_solution_set().len() as u16;
+        assert!(solutions > 0);
+        for (cell, counts) in frequency.iter().enumerate() {
+            let expected = if board_layout.data()[cell] == 1 {
+                0
+            } else {
+                solutions
+            };
+            assert_eq!(expected, counts.iter().sum::<u16>());
+        }
+    }
+
+    #[test]
+    fn every_standard_piece_is_critical() {
+        // Arrange & Act
+        let critical = critical_pieces(6, 10);
+
+        // Assert
+        assert_eq!(vec![0, 1, 2, 3, 4, 5, 6, 7], critical);
+    }
+
+    #[test]
+    fn critical_pieces_on_finds_redundant_piece() {
+        // Arrange
+        // The three pieces of 5 cells tile the board, so the 6 cell piece is redundant
+        let board = BoardModel::from_layout(Array2D::repeat_row(&[0; 5], 3));
+        let pieces = piece::create_piece_models()[0..4].to_vec();
+
+        // Act
+        let critical = critical_pieces_on(board, pieces);
+
+        // Assert
+        assert_eq!(vec![1, 2, 3], critical);
+    }
+
+    #[test]
+    fn most_common_placement_has_maximum_frequency() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(4, 11);
+
+        // Act
+        let (placement, count) = solver.most_common_placement();
+
+        // Assert
+        let solutions = solver.get_solution_set();
+        let count_of = |placement: &PieceBoardPosition| {
+            solutions
+                .iter()
+                .filter(|solution| solution.contains(placement))
+                .count()
+        };
+        assert_eq!(count_of(&placement), count);
+        for other in solutions.iter().flatten() {
+            assert!(count_of(other) <= count);
+        }
+    }
+
+    #[test]
+    fn find_solution_set_to_file_round_trip() {
+        // Arrange
+        let path = std::env::temp_dir().join("dfsolver_find_solution_set_to_file.txt");
+        let mut solver = SolverSingleThreaded::new(4, 11);
+        let mut expected_result = solver.clone();
+        expected_result.find_solution_set();
+
+        // Act
+        let written = solver.find_solution_set_to_file(&path).unwrap();
+        let solutions: Vec<Vec<PieceBoardPosition>> = solver
+            .solutions_from_file(&path)
+            .unwrap()
+            .collect::<io::Result<_>>()
+            .unwrap();
+
+        // Assert
+        let lines = std::fs::read_to_string(&path).unwrap().lines().count();
+        assert_eq!(expected_result.get_solution_set().len(), written);
+        assert_eq!(written, lines);
+        assert!(solver.get_solution_set().is_empty());
+        assert_eq!(expected_result.get_solution_set(), &solutions);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn find_first_solution_stepped_reaches_complete_board() {
+        // Arrange
+        let mut max_steps = 1;
+
+        let result = loop {
+            // Act
+            let result = SolverSingleThreaded::new(6, 10).find_first_solution_stepped(max_steps);
+
+            // Assert
+            assert!(result.steps <= max_steps);
+            if result.is_solved {
+                break result;
+            }
+            assert_eq!(max_steps, result.steps);
+            assert!(!board::is_board_complete(&result.board_layout));
+            max_steps *= 2;
+        };
+
+        assert!(board::is_board_complete(&result.board_layout));
+        // Stepping again from the start stops at the same step
+        let mut solver = SolverSingleThreaded::new(6, 10);
+        let mut steps = 0;
+        while !solver.find_first_solution_stepped(1).is_solved {
+            steps += 1;
+        }
+        assert_eq!(result.steps, steps + 1);
+    }
+
+    #[test]
+    fn find_weighted_solution_prefers_corner_placement() {
+        // Arrange
+        let solver = SolverSingleThreaded::new(4, 11);
+        let mut expected_result = solver.clone();
+        expected_result.find_solution_set();
+        let weights = PlacementWeights::new().with_weight("3x3 L", (0, 0), 1000);
+        let covers_corner = |solution: &Vec<PieceBoardPosition>| {
+            solution.iter().any(|placement| {
+                placement.get_name() == "3x3 L" && placement.covered_cells().contains(&(0, 0))
+            })
+        };
+
+        // Act
+        let solution = solver.find_weighted_solution(&weights).unwrap();
+
+        // Assert
+        // The first solution found without weights does not put the piece in the corner
+        assert!(!covers_corner(&expected_result.get_solution_set()[0]));
+        assert!(covers_corner(&solution));
+        assert!(expected_result.get_solution_set().contains(&solution));
+    }
+
+    #[test]
+    fn find_weighted_solution_prefers_placement_away_from_first_cell() {
+        // Arrange
+        let solver = SolverSingleThreaded::new(4, 11);
+        let mut expected_result = solver.clone();
+        expected_result.find_solution_set();
+        // The bottom right corner is one of the last cells filled from the top left
+        let weights = PlacementWeights::new().with_weight("2x3 Middle Hole", (5, 6), 1000);
+        let covers_corner = |solution: &Vec<PieceBoardPosition>| {
+            solution.iter().any(|placement| {
+                placement.get_name() == "2x3 Middle Hole"
+                    && placement.covered_cells().contains(&(5, 6))
+            })
+        };
+
+        // Act
+        let solution = solver.find_weighted_solution(&weights).unwrap();
+
+        // Assert
+        assert!(!covers_corner(&expected_result.get_solution_set()[0]));
+        assert!(covers_corner(&solution));
+        assert!(expected_result.get_solution_set().contains(&solution));
+    }
+
+    #[test]
+    fn find_weighted_solution_without_weights() {
+        // Arrange
+        let solver = SolverSingleThreaded::new(6, 10);
+        let expected_result = solver.clone().next_solution();
+
+        // Act
+        let solution = solver.find_weighted_solution(&PlacementWeights::new());
+
+        // Assert
+        assert_eq!(expected_result, solution);
+    }
+
+    #[test]
+    fn collect_solution_grids_into_decodes_to_tilings() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(6, 10);
+        let mut expected_result = solver.clone();
+        expected_result.find_solution_set();
+        let board_layout = BoardModel::new(6, 10).get_board_layout().clone();
+        let piece_areas: Vec<usize> = solver.get_pieces().iter().map(|p| p.area()).collect();
+        let mut grids = vec![[0; 49]];
+
+        // Act
+        solver.collect_solution_grids_into(&mut grids);
+
+        // Assert
+        // The existing grid is kept and the solutions are appended after it
+        assert_eq!(expected_result.get_solution_set().len() + 1, grids.len());
+        assert!(solver.get_solution_set().is_empty());
+        for grid in &grids[1..] {
+            for (cell, &piece_id) in grid.iter().enumerate() {
+                let is_reserved = board_layout.data()[cell] == 1;
+                assert_eq!(is_reserved, piece_id == 0);
+            }
+            for (index, &area) in piece_areas.iter().enumerate() {
+                let covered = grid.iter().filter(|&&id| id == index as u8 + 1).count();
+                assert_eq!(area, covered);
+            }
         }
     }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialized_search_resumes() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(6, 10).with_validity_cache();
+        let mut expected_result = SolverSingleThreaded::new(6, 10);
+        expected_result.find_solution_set();
+
+        let first_solutions: Vec<Vec<PieceBoardPosition>> =
+            (0..3).map(|_| solver.next_solution().unwrap()).collect();
+        let saved_search = serde_json::to_string(&solver).unwrap();
+        drop(solver);
+
+        // Act
+        let mut resumed_solver: SolverSingleThreaded = serde_json::from_str(&saved_search).unwrap();
+        resumed_solver.find_solution_set();
+
+        // Assert
+        let mut solutions = first_solutions;
+        solutions.extend(resumed_solver.get_solution_set().iter().cloned());
+        assert_eq!(4, resumed_solver.get_solution_set().len());
+        assert_eq!(expected_result.get_solution_set(), &solutions);
+        assert!(resumed_solver.get_validity_cache().is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn solution_set_json_round_trip() {
+        // Arrange
+        let mut solver = SolverSingleThreaded::new(6, 10);
+        solver.find_solution_set();
+
+        // Act
+        let json = serde_json::to_string(solver.get_solution_set()).unwrap();
+        let solution_set: Vec<Vec<PieceBoardPosition>> = serde_json::from_str(&json).unwrap();
+
+        // Assert
+        assert!(json.contains("\"name\":\"2x4 L\""));
+        assert_eq!(solver.get_solution_set(), &solution_set);
+    }
 }
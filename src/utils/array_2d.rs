@@ -1,6 +1,8 @@
 use core::fmt;
 use std::{mem, ops};
 
+use crate::error::DfError;
+
 /// Creates an `Array2D` struct from a passed in array like object.
 /// The array data is stored in a 1D `vec`, and the array shape is stored in a `Shape` struct.
 ///
@@ -19,32 +21,50 @@ use std::{mem, ops};
 /// let expected_result: Array2D = Array2D::new(Shape { rows: 3, cols: 3 }, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
 /// assert_eq!(expected_result, array);
 /// ```
+///
+/// Calling the macro with no rows creates an empty `Array2D` with a shape of 0 x 0. Any attempt
+/// to index into it panics as the index is outside the bounds of the array.
+///
+/// ```
+/// # use dfsolver::{utils::array_2d::*, array2D};
+/// let array: Array2D = array2D!();
+///
+/// assert_eq!(&Shape { rows: 0, cols: 0 }, array.shape());
+/// assert!(array.data().is_empty());
+/// ```
 #[macro_export]
 macro_rules! array2D {
+    () => {
+        $crate::utils::array_2d::Array2D::new(
+            $crate::utils::array_2d::Shape { rows: 0, cols: 0 },
+            Vec::new(),
+        )
+    };
     ( $( $row_vector: tt ),* ) => {
         {
             use $crate::utils::array_2d::{ Array2D, Shape };
             let mut data = Vec::new();
-            let mut shape = Shape { rows: 0, cols: 0 };
+            let mut rows: usize = 0;
+            let mut cols: usize = 0;
 
             $(
-                let new_row = vec!$row_vector;
+                let mut new_row = vec!$row_vector;
 
                 // Set the length of the row (i.e. number of columns) if none has been set.
                 // If set, assert that rows all have the same legnth.
-                if shape.cols == 0 {
-                    shape.cols = new_row.len();
+                if rows == 0 {
+                    cols = new_row.len();
                 } else {
-                    assert!(new_row.len() == shape.cols, "Supplied matrix had inconsistent row lengths")
+                    assert!(new_row.len() == cols, "Supplied matrix had inconsistent row lengths")
                 }
 
                 // Counts the number of rows in the matrix
-                shape.rows = shape.rows + 1;
+                rows += 1;
 
-                data.append(&mut vec!$row_vector);
+                data.append(&mut new_row);
             )*
 
-            Array2D::new(shape, data)
+            Array2D::new(Shape { rows, cols }, data)
         }
     };
 }
@@ -56,11 +76,20 @@ macro_rules! array2D {
 /// * `rows` - the number of rows in the matrix (M dimension).
 /// * `cols` - the number of columns in the matrix (N dimension).
 #[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Shape {
     pub rows: usize,
     pub cols: usize,
 }
 
+impl Shape {
+    /// Returns the index in the data array of an array of the shape of the element at the row
+    /// and column, as `Array2D::to_index` does.
+    pub fn to_index(&self, row: usize, col: usize) -> usize {
+        row * self.cols + col
+    }
+}
+
 /// An enumeration of the axes which can be flipped along.
 ///
 /// * Flipping along the X axes corresponds to vertically flipping the matrix.
@@ -77,6 +106,7 @@ pub enum Axes {
 /// * `shape` - the shape of the M x N array where M is the number of rows, and N is the number of columns
 /// * `data` - a 1D `Vec` which holds the data of the array
 #[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Array2D {
     shape: Shape,
     data: Vec<u8>,
@@ -87,7 +117,76 @@ impl Array2D {
         Array2D { shape, data }
     }
 
+    /// Creates an `Array2D` from row slices built at runtime, e.g. a `Vec<Vec<u8>>` parsed from a
+    /// file. Like the `array2D!` macro, every row must have the same length, but a ragged row
+    /// returns an error rather than panicking. An empty slice of rows also returns an error; use
+    /// `array2D!()` for an empty array.
+    ///
+    /// # Arguments
+    /// * `rows` - The rows of the array, in order from the top row.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D, error::DfError};
+    /// let array = Array2D::from_rows(&[[1, 2, 3], [4, 5, 6]]);
+    /// assert_eq!(Ok(array2D!([1, 2, 3], [4, 5, 6])), array);
+    ///
+    /// let ragged: [&[u8]; 2] = [&[1, 2, 3], &[4, 5]];
+    /// let expected_result = DfError::RaggedRow { row: 1, expected_len: 3, found_len: 2 };
+    /// assert_eq!(Err(expected_result), Array2D::from_rows(&ragged));
+    /// ```
+    pub fn from_rows<R: AsRef<[u8]>>(rows: &[R]) -> Result<Array2D, DfError> {
+        let cols = match rows.first() {
+            Some(row) => row.as_ref().len(),
+            None => return Err(DfError::NoRows),
+        };
+        let mut data: Vec<u8> = Vec::with_capacity(rows.len() * cols);
+
+        for (index, row) in rows.iter().enumerate() {
+            let row = row.as_ref();
+            if row.len() != cols {
+                return Err(DfError::RaggedRow {
+                    row: index,
+                    expected_len: cols,
+                    found_len: row.len(),
+                });
+            }
+            data.extend_from_slice(row);
+        }
+
+        Ok(Array2D::new(
+            Shape {
+                rows: rows.len(),
+                cols,
+            },
+            data,
+        ))
+    }
+
+    /// Creates an `Array2D` by stacking the same row a number of times.
+    ///
+    /// # Arguments
+    /// * `row` - The row to be repeated.
+    /// * `times` - The number of rows in the array.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let array = Array2D::repeat_row(&[0, 1, 0], 2);
+    /// assert_eq!(array2D!([0, 1, 0], [0, 1, 0]), array);
+    /// ```
+    pub fn repeat_row(row: &[u8], times: usize) -> Array2D {
+        Array2D::new(
+            Shape {
+                rows: times,
+                cols: row.len(),
+            },
+            row.repeat(times),
+        )
+    }
+
     /// Returns the element at the specified index of the `Array2D` it is called on.
+    /// See `get_checked` for a variant which does not panic.
     ///
     /// # Arguments
     /// * `row` - The row of the desired element.
@@ -113,11 +212,29 @@ impl Array2D {
     /// matrix.get(1, 4);
     /// ```
     pub fn get(&self, row: usize, col: usize) -> u8 {
-        if row > self.shape.rows - 1 || col > self.shape.cols - 1 {
-            panic!("Indexing outside bounds of array");
+        self[(row, col)]
+    }
+
+    /// Returns the element at the specified index of the `Array2D` it is called on, or `None` if
+    /// the index is outside the bounds of the array. Use `get` for the panicking variant.
+    ///
+    /// # Arguments
+    /// * `row` - The row of the desired element.
+    /// * `col` - The column of the desired element.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6]);
+    /// assert_eq!(Some(2), matrix.get_checked(0, 1));
+    /// assert_eq!(None, matrix.get_checked(1, 4));
+    /// ```
+    pub fn get_checked(&self, row: usize, col: usize) -> Option<u8> {
+        if row >= self.shape.rows || col >= self.shape.cols {
+            return None;
         }
 
-        self.data[self.shape.cols * row + col]
+        Some(self.data[self.to_index(row, col)])
     }
 
     /// Sets the value of the element at the specified index of the `Array2D` it is called on.
@@ -146,12 +263,64 @@ impl Array2D {
     /// matrix.set((1, 3), 5);
     /// ```
     pub fn set(&mut self, board_position: (usize, usize), new_value: u8) {
+        self[board_position] = new_value;
+    }
+
+    /// Sets the value of the element at the specified index of the `Array2D` it is called on if
+    /// the index is within the bounds of the array. Use `set` for the panicking variant.
+    ///
+    /// Returns whether the element was written.
+    ///
+    /// # Arguments
+    /// * `board_position` - The row and column of the element to be set.
+    /// * `new_value` - The value that the element is to be set to.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let mut matrix: Array2D = array2D!([0, 0, 0], [0, 0, 0]);
+    /// assert!(matrix.set_checked((1, 2), 5));
+    /// assert!(!matrix.set_checked((2, 0), 5));
+    /// assert_eq!(array2D!([0, 0, 0], [0, 0, 5]), matrix);
+    /// ```
+    pub fn set_checked(&mut self, board_position: (usize, usize), new_value: u8) -> bool {
         let (row, col) = board_position;
-        if row > self.shape.rows - 1 || col > self.shape.cols - 1 {
-            panic!("Indexing outside bounds of array");
+        if row >= self.shape.rows || col >= self.shape.cols {
+            return false;
         }
 
-        self.data[self.shape.cols * row + col] = new_value;
+        let index = self.to_index(row, col);
+        self.data[index] = new_value;
+        true
+    }
+
+    /// Returns the index in the data array of the element at the row and column. Elements are
+    /// stored row by row, so this is `row * cols + col`. The row and column are not checked
+    /// against the bounds of the array.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6]);
+    /// assert_eq!(4, matrix.to_index(1, 1));
+    /// assert_eq!(5, matrix.data()[matrix.to_index(1, 1)]);
+    /// ```
+    pub fn to_index(&self, row: usize, col: usize) -> usize {
+        self.shape.to_index(row, col)
+    }
+
+    /// Returns the row and column of the element at the index in the data array. This is the
+    /// inverse of `to_index`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6]);
+    /// assert_eq!((1, 2), matrix.from_index(5));
+    /// assert_eq!(matrix.to_index(1, 2), 5);
+    /// ```
+    pub fn from_index(&self, index: usize) -> (usize, usize) {
+        (index / self.shape.cols, index % self.shape.cols)
     }
 
     /// Returns a immutable reference to the data array of the `Array2D` it is called on.
@@ -160,6 +329,15 @@ impl Array2D {
     }
 
     /// Returns a immutable reference to the shape of `Array2D` it is called on.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6]);
+    /// assert_eq!(2, matrix.shape().rows);
+    /// assert_eq!(3, matrix.shape().cols);
+    /// assert_eq!(matrix.shape().rows * matrix.shape().cols, matrix.data().len());
+    /// ```
     pub fn shape(&self) -> &Shape {
         &self.shape
     }
@@ -198,24 +376,21 @@ impl Array2D {
     /// let expected_result: Array2D = array2D!([3, 2, 1], [6, 5, 4], [9, 8, 7]);
     /// assert_eq!(expected_result, matrix);
     /// ```
-
     pub fn flip(&mut self, axes: Axes) {
         match axes {
             Axes::X => {
-                for row_index in 0..self.shape.cols / 2 {
+                for row_index in 0..self.shape.rows / 2 {
                     for col_index in 0..self.shape.cols {
-                        self.data.swap(
-                            row_index * self.shape.cols + col_index,
-                            ((self.shape.rows - 1) - row_index) * self.shape.cols + col_index,
-                        );
+                        let top = self.to_index(row_index, col_index);
+                        let bottom = self.to_index((self.shape.rows - 1) - row_index, col_index);
+                        self.data.swap(top, bottom);
                     }
                 }
             }
             Axes::Y => {
                 for row_index in 0..self.shape.rows {
-                    let row_slice = &mut self.data[row_index * self.shape.cols
-                        ..row_index * self.shape.cols + self.shape.cols];
-                    row_slice.reverse();
+                    let start = self.to_index(row_index, 0);
+                    self.data[start..start + self.shape.cols].reverse();
                 }
             }
         }
@@ -278,6 +453,38 @@ impl Array2D {
         mem::swap(&mut self.shape.cols, &mut self.shape.rows);
     }
 
+    /// Writes the transpose of the `Array2D` it is called on into `out`, reusing the allocation
+    /// of `out` rather than creating a new array.
+    ///
+    /// # Arguments
+    /// `out` - The array the transpose is written to. Its shape must be the swapped shape of the
+    /// array being transposed.
+    ///
+    /// # Panics!
+    /// Will panic if `out` does not have the swapped shape of the array being transposed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let matrix: Array2D = array2D!([0, 1, 2, 3], [4, 5, 6, 7]);
+    /// let mut out: Array2D = array2D!([0, 0], [0, 0], [0, 0], [0, 0]);
+    /// matrix.transpose_into(&mut out);
+    /// let expected_result: Array2D = array2D!([0, 4], [1, 5], [2, 6], [3, 7]);
+    /// assert_eq!(expected_result, out);
+    /// ```
+    pub fn transpose_into(&self, out: &mut Array2D) {
+        if out.shape.rows != self.shape.cols || out.shape.cols != self.shape.rows {
+            panic!("Output array must have the swapped dimensions of the array being transposed");
+        }
+
+        for row in 0..self.shape.rows {
+            for col in 0..self.shape.cols {
+                let out_index = out.to_index(col, row);
+                out.data[out_index] = self.data[self.to_index(row, col)];
+            }
+        }
+    }
+
     /// Rotates the `Array2D` it is called on by k x 90 degrees.
     ///
     /// # Arguments
@@ -299,7 +506,7 @@ impl Array2D {
         let k = k % 4;
 
         match k {
-            0 => return,
+            0 => (),
 
             1 | -3 => {
                 self.flip(Axes::Y);
@@ -322,195 +529,907 @@ impl Array2D {
         }
     }
 
-    pub fn append_array(&mut self, mut other: Array2D, axes: Axes) {
-        match axes {
-            Axes::X => {
-                if self.shape.cols == other.shape().cols {
-                    self.data.append(&mut other.get_mut_data());
-                    self.shape = Shape {
-                        rows: self.shape.rows + other.shape().rows,
-                        cols: self.shape.cols,
-                    }
-                } else {
-                    panic!("Tried to append Array2D with different row dimension.")
-                }
-            }
-            Axes::Y => {
-                if self.shape.rows == other.shape().rows {
-                    let mut new_array: Vec<u8> = Vec::new();
+    /// Returns the eight symmetries (rotations and reflections) of the `Array2D` it is called on.
+    /// The first four are the anti-clockwise quarter turns of the array and the last four are the
+    /// quarter turns of the horizontally flipped array. Duplicate symmetries are not removed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6]);
+    /// let symmetries = matrix.symmetries();
+    ///
+    /// assert_eq!(8, symmetries.len());
+    /// assert_eq!(matrix, symmetries[0]);
+    /// assert!(symmetries.contains(&array2D!([3, 2, 1], [6, 5, 4])));
+    /// ```
+    pub fn symmetries(&self) -> Vec<Array2D> {
+        let mut symmetries: Vec<Array2D> = Vec::new();
+        let mut symmetry = self.clone();
 
-                    for row_index in 0..self.shape.rows {
-                        let mut new_row_self = self.data[row_index * self.shape.cols
-                            ..row_index * self.shape.cols + self.shape.cols]
-                            .to_vec();
+        for _ in 0..4 {
+            symmetries.push(symmetry.clone());
+            symmetry.rotate90(1);
+        }
 
-                        let mut new_row_other = other.data[row_index * other.shape.cols
-                            ..row_index * other.shape.cols + other.shape.cols]
-                            .to_vec();
+        symmetry.flip(Axes::Y);
+        for _ in 0..4 {
+            symmetries.push(symmetry.clone());
+            symmetry.rotate90(1);
+        }
 
-                        new_row_self.append(&mut new_row_other);
-                        new_array.append(&mut new_row_self);
-                    }
+        symmetries
+    }
 
-                    self.data = new_array;
-                    self.shape = Shape {
-                        rows: self.shape.rows,
-                        cols: self.shape.cols + other.shape().cols,
-                    }
-                } else {
-                    panic!("Tried to append Array2D with different column dimension.")
-                }
-            }
+    /// Adds an `Array2D` to the `Array2D` it is called on element wise.
+    /// Unlike the `+` operator, an error is returned rather than panicking if the arrays have
+    /// different dimensions.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D, error::DfError};
+    /// let matrix: Array2D = array2D!([0, 1, 2], [3, 4, 5]);
+    /// let matrix2: Array2D = array2D!([2, 2], [2, 2]);
+    /// let expected_result = DfError::ShapeMismatch {
+    ///     expected: Shape { rows: 2, cols: 3 },
+    ///     found: Shape { rows: 2, cols: 2 },
+    /// };
+    /// assert_eq!(Err(expected_result), matrix.try_add(matrix2));
+    /// ```
+    pub fn try_add(self, other_array: Array2D) -> Result<Array2D, DfError> {
+        if self.shape != other_array.shape {
+            return Err(DfError::ShapeMismatch {
+                expected: self.shape,
+                found: other_array.shape,
+            });
         }
-    }
-}
 
-impl ops::Add<Array2D> for Array2D {
-    type Output = Array2D;
+        Ok(self + other_array)
+    }
 
-    /// Adds an `Array2D` to the `Array2D` it is called on element wise.
+    /// Returns an iterator over every `win_rows` x `win_cols` window of the `Array2D` it is called
+    /// on, in row-major order of the window's top left element.
     ///
     /// # Arguments
-    /// `other_array` - An Array2D to be added. Both arrays must have the same dimension.
+    /// * `win_rows` - The number of rows in each window.
+    /// * `win_cols` - The number of columns in each window.
     ///
     /// # Panics!
-    /// Will panic if 2D arrays with different dimensions are added together.
+    /// Will panic if the window is empty or larger than the array.
     ///
     /// # Examples
-    /// Adding 2D arrays of the same dimension together:
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6], [7, 8, 9]);
+    /// let windows: Vec<Array2D> = matrix.windows(2, 2).collect();
+    /// assert_eq!(4, windows.len());
+    /// assert_eq!(array2D!([1, 2], [4, 5]), windows[0]);
+    /// ```
+    pub fn windows(&self, win_rows: usize, win_cols: usize) -> impl Iterator<Item = Array2D> + '_ {
+        if win_rows == 0
+            || win_cols == 0
+            || win_rows > self.shape.rows
+            || win_cols > self.shape.cols
+        {
+            panic!("Window dimensions must be non-zero and no larger than the array");
+        }
+
+        let row_positions = self.shape.rows - win_rows + 1;
+        let col_positions = self.shape.cols - win_cols + 1;
+
+        (0..row_positions * col_positions).map(move |index| {
+            let (start_row, start_col) = (index / col_positions, index % col_positions);
+            let mut data: Vec<u8> = Vec::with_capacity(win_rows * win_cols);
+            for row in start_row..start_row + win_rows {
+                let start = self.to_index(row, start_col);
+                data.extend_from_slice(&self.data[start..start + win_cols]);
+            }
+
+            Array2D::new(
+                Shape {
+                    rows: win_rows,
+                    cols: win_cols,
+                },
+                data,
+            )
+        })
+    }
+
+    /// Returns an iterator over the rows of the `Array2D` it is called on, from the top row.
     ///
+    /// # Examples
     /// ```
     /// # use dfsolver::{utils::array_2d::*, array2D};
-    /// let matrix: Array2D = array2D!([0, 1, 2], [3, 4, 5]);
-    /// let matrix2: Array2D = array2D!([2, 2, 2], [2, 2, 2]);
-    /// let expected_result: Array2D = array2D!([2, 3, 4], [5, 6, 7]);
-    /// assert_eq!(expected_result, matrix + matrix2);
+    /// let matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6]);
+    /// let rows: Vec<&[u8]> = matrix.iter_rows().collect();
+    /// assert_eq!(vec![&[1, 2, 3][..], &[4, 5, 6][..]], rows);
     /// ```
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        (0..self.shape.rows).map(move |row| {
+            let start = self.to_index(row, 0);
+            &self.data[start..start + self.shape.cols]
+        })
+    }
+
+    /// Returns an iterator over the columns of the `Array2D` it is called on, from the left
+    /// column. The elements of a column are not next to each other in the data array, so each
+    /// column is copied into a `Vec`.
     ///
-    /// Adding together 2D arrays with different dimensions:
-    /// ```should_panic
+    /// # Examples
+    /// ```
     /// # use dfsolver::{utils::array_2d::*, array2D};
-    /// let matrix: Array2D = array2D!([0, 1, 2], [3, 4, 5]);
-    /// let matrix2: Array2D = array2D!([2, 2, 2, 2], [2, 2, 2, 2]);
-    /// let _ = matrix + matrix2;
+    /// let matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6]);
+    /// let cols: Vec<Vec<u8>> = matrix.iter_cols().collect();
+    /// assert_eq!(vec![vec![1, 4], vec![2, 5], vec![3, 6]], cols);
     /// ```
-    fn add(self, other_array: Array2D) -> Array2D {
-        if self.shape != other_array.shape {
-            panic!("Array dimensions must be the same for arrays to be added element wise");
-        }
+    pub fn iter_cols(&self) -> impl Iterator<Item = Vec<u8>> + '_ {
+        (0..self.shape.cols)
+            .map(move |col| (0..self.shape.rows).map(|row| self.get(row, col)).collect())
+    }
 
-        let new_array: Vec<u8> = self
-            .data
+    /// Returns the number of elements equal to `value` in the array.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let matrix: Array2D = array2D!([1, 1, 1], [1, 0, 1], [1, 0, 0]);
+    /// assert_eq!(3, matrix.count(0));
+    /// ```
+    pub fn count(&self, value: u8) -> usize {
+        self.data
             .iter()
-            .zip(other_array.data.iter())
-            .map(|(a, b)| *a + *b)
-            .collect();
+            .filter(|&&element| element == value)
+            .count()
+    }
 
-        // Struct update syntax - uses values from another instance
-        Array2D {
-            data: new_array,
-            ..self
+    /// Returns the number of elements equal to `value` in the `rows` x `cols` region whose top
+    /// left element is at `(top, left)`.
+    ///
+    /// # Panics!
+    /// Function will panic! if the region extends outside the bounds of the array.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let matrix: Array2D = array2D!([1, 1, 1], [1, 0, 1], [1, 0, 0]);
+    /// assert_eq!(3, matrix.count_in_region(1, 1, 2, 2, 0));
+    /// ```
+    ///
+    /// Counting in a region outside the bounds of the array.
+    /// ```should_panic
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let matrix: Array2D = array2D!([1, 1, 1], [1, 0, 1], [1, 0, 0]);
+    /// matrix.count_in_region(2, 2, 2, 2, 0);
+    /// ```
+    pub fn count_in_region(
+        &self,
+        top: usize,
+        left: usize,
+        rows: usize,
+        cols: usize,
+        value: u8,
+    ) -> usize {
+        if top + rows > self.shape.rows || left + cols > self.shape.cols {
+            panic!("Region extends outside bounds of array");
         }
+
+        (top..top + rows)
+            .map(|row| {
+                self.data[self.to_index(row, left)..self.to_index(row, left + cols)]
+                    .iter()
+                    .filter(|&&element| element == value)
+                    .count()
+            })
+            .sum()
     }
-}
 
-impl fmt::Display for Array2D {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "[")?;
+    /// Returns the smallest sub-array containing every non-zero element, removing the rows and
+    /// columns of zeros around the edges. An array of only zeros returns an empty array.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let matrix: Array2D = array2D!([0, 0, 0, 0], [0, 1, 1, 0], [0, 0, 1, 0]);
+    /// assert_eq!(array2D!([1, 1], [0, 1]), matrix.trimmed());
+    /// ```
+    pub fn trimmed(&self) -> Array2D {
+        let filled: Vec<(usize, usize)> = (0..self.shape.rows)
+            .flat_map(|row| (0..self.shape.cols).map(move |col| (row, col)))
+            .filter(|&(row, col)| self.get(row, col) != 0)
+            .collect();
 
-        for row_index in 0..self.shape.rows {
-            // For every element except the first, add a comma.
-            // Use the ? operator to return on errors.
-            if row_index != 0 {
-                write!(f, ",\n ")?;
-            }
+        let (Some(min_row), Some(max_row), Some(min_col), Some(max_col)) = (
+            filled.iter().map(|cell| cell.0).min(),
+            filled.iter().map(|cell| cell.0).max(),
+            filled.iter().map(|cell| cell.1).min(),
+            filled.iter().map(|cell| cell.1).max(),
+        ) else {
+            return Array2D::new(Shape { rows: 0, cols: 0 }, Vec::new());
+        };
+
+        let mut data: Vec<u8> = Vec::new();
+        for row in min_row..=max_row {
+            data.extend_from_slice(
+                &self.data[self.to_index(row, min_col)..=self.to_index(row, max_col)],
+            );
+        }
 
-            write!(f, "[")?;
+        Array2D::new(
+            Shape {
+                rows: max_row - min_row + 1,
+                cols: max_col - min_col + 1,
+            },
+            data,
+        )
+    }
 
-            for col_index in 0..self.shape.cols {
-                if col_index != 0 {
-                    write!(f, ", ")?;
-                }
-                write!(f, "{}", self.get(row_index, col_index))?;
-            }
-            write!(f, "]")?;
+    /// Returns the occupancy of the array packed into the bits of a `u64`, where the bit
+    /// `row * cols + col` is set if the element at `(row, col)` is not 0. A 7 x 7 board uses the
+    /// low 49 bits.
+    ///
+    /// # Panics!
+    /// Function will panic! if the array has more than 64 elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let matrix: Array2D = array2D!([1, 0, 0], [0, 2, 0]);
+    /// assert_eq!(0b010_001, matrix.to_bitmask());
+    /// ```
+    pub fn to_bitmask(&self) -> u64 {
+        if self.data.len() > 64 {
+            panic!(
+                "A {} x {} array does not fit in a 64 bit mask",
+                self.shape.rows, self.shape.cols
+            );
         }
-        return write!(f, "]");
-    }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        self.data
+            .iter()
+            .enumerate()
+            .filter(|(_, &element)| element != 0)
+            .fold(0, |mask, (index, _)| mask | 1 << index)
+    }
 
-    #[test]
-    fn get_value() {
-        // Arrange
-        let matrix: Array2D = array2D!([0, 0, 0], [0, 5, 0], [0, 0, 0]);
+    /// Returns an array of the shape with a 1 for every set bit of the mask and a 0 for every
+    /// other element, reversing `to_bitmask` for arrays of 0s and 1s. Bits beyond the number of
+    /// elements are ignored.
+    ///
+    /// # Panics!
+    /// Function will panic! if the shape has more than 64 elements.
+    ///
+    /// # Arguments
+    /// * `shape` - The shape of the array.
+    /// * `mask` - The occupancy of the array, as returned by `to_bitmask`.
+    pub fn from_bitmask(shape: Shape, mask: u64) -> Array2D {
+        let len = shape.rows * shape.cols;
+        if len > 64 {
+            panic!(
+                "A {} x {} array does not fit in a 64 bit mask",
+                shape.rows, shape.cols
+            );
+        }
 
-        // Act
-        let a = matrix.get(1, 1);
+        let data = (0..len).map(|index| (mask >> index & 1) as u8).collect();
+        Array2D::new(shape, data)
+    }
 
-        // Assert
+    /// Returns a copy of the `rows` x `cols` region whose top left element is at `(row, col)`.
+    ///
+    /// # Panics!
+    /// Function will panic! if the region extends outside the bounds of the array.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6], [7, 8, 9]);
+    /// assert_eq!(array2D!([5, 6], [8, 9]), matrix.crop(1, 1, 2, 2));
+    /// ```
+    ///
+    /// Cropping a region outside the bounds of the array.
+    /// ```should_panic
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6], [7, 8, 9]);
+    /// matrix.crop(2, 2, 2, 2);
+    /// ```
+    pub fn crop(&self, row: usize, col: usize, rows: usize, cols: usize) -> Array2D {
+        if row + rows > self.shape.rows || col + cols > self.shape.cols {
+            panic!("Region extends outside bounds of array");
+        }
+
+        let data = (row..row + rows)
+            .flat_map(|row| {
+                self.data[self.to_index(row, col)..self.to_index(row, col + cols)].iter()
+            })
+            .copied()
+            .collect();
+
+        Array2D::new(Shape { rows, cols }, data)
+    }
+
+    /// Returns a larger array with the array it is called on surrounded by a border of `fill`.
+    ///
+    /// # Arguments
+    /// * `top` - The number of rows added above the array.
+    /// * `bottom` - The number of rows added below the array.
+    /// * `left` - The number of columns added to the left of the array.
+    /// * `right` - The number of columns added to the right of the array.
+    /// * `fill` - The value of every element of the border.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let matrix: Array2D = array2D!([1, 2], [3, 4]);
+    /// assert_eq!(array2D!([0, 0, 0], [1, 2, 0], [3, 4, 0]), matrix.pad(1, 0, 0, 1, 0));
+    /// ```
+    pub fn pad(&self, top: usize, bottom: usize, left: usize, right: usize, fill: u8) -> Array2D {
+        let shape = Shape {
+            rows: top + self.shape.rows + bottom,
+            cols: left + self.shape.cols + right,
+        };
+        let mut data = vec![fill; shape.rows * shape.cols];
+
+        for (row_index, row) in self.iter_rows().enumerate() {
+            let start = shape.to_index(top + row_index, left);
+            data[start..start + self.shape.cols].copy_from_slice(row);
+        }
+
+        Array2D::new(shape, data)
+    }
+
+    /// Appends another `Array2D` to the `Array2D` it is called on.
+    ///
+    /// # Arguments
+    /// * `other` - The array to be appended.
+    /// * `axes` - `Axes::X` stacks `other` below the array, `Axes::Y` joins `other` to the right
+    ///   of the array.
+    ///
+    /// # Panics!
+    /// Function will panic! if stacking along `Axes::X` arrays with a different number of
+    /// columns, or joining along `Axes::Y` arrays with a different number of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let mut matrix: Array2D = array2D!([1, 2], [3, 4]);
+    /// matrix.append_array(array2D!([5, 6]), Axes::X);
+    /// assert_eq!(array2D!([1, 2], [3, 4], [5, 6]), matrix);
+    ///
+    /// matrix.append_array(array2D!([7], [8], [9]), Axes::Y);
+    /// assert_eq!(array2D!([1, 2, 7], [3, 4, 8], [5, 6, 9]), matrix);
+    /// ```
+    pub fn append_array(&mut self, mut other: Array2D, axes: Axes) {
+        match axes {
+            Axes::X => {
+                if self.shape.cols == other.shape().cols {
+                    self.data.append(other.get_mut_data());
+                    self.shape = Shape {
+                        rows: self.shape.rows + other.shape().rows,
+                        cols: self.shape.cols,
+                    }
+                } else {
+                    panic!(
+                        "Tried to stack an Array2D with {} columns below an Array2D with {} columns.",
+                        other.shape().cols,
+                        self.shape.cols
+                    )
+                }
+            }
+            Axes::Y => {
+                if self.shape.rows == other.shape().rows {
+                    let mut new_array: Vec<u8> =
+                        Vec::with_capacity(self.data.len() + other.data.len());
+
+                    for (row_self, row_other) in self.iter_rows().zip(other.iter_rows()) {
+                        new_array.extend_from_slice(row_self);
+                        new_array.extend_from_slice(row_other);
+                    }
+
+                    self.data = new_array;
+                    self.shape = Shape {
+                        rows: self.shape.rows,
+                        cols: self.shape.cols + other.shape().cols,
+                    }
+                } else {
+                    panic!(
+                        "Tried to join an Array2D with {} rows to an Array2D with {} rows.",
+                        other.shape().rows,
+                        self.shape.rows
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// Indexes the element at a `(row, col)` position, panicking outside the bounds of the array
+/// like `Array2D::get`.
+///
+/// # Examples
+/// ```
+/// # use dfsolver::{utils::array_2d::*, array2D};
+/// let mut matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6]);
+/// matrix[(0, 0)] = 5;
+/// assert_eq!(5, matrix[(0, 0)]);
+/// assert_eq!(6, matrix[(1, 2)]);
+/// ```
+impl ops::Index<(usize, usize)> for Array2D {
+    type Output = u8;
+
+    fn index(&self, (row, col): (usize, usize)) -> &u8 {
+        if row >= self.shape.rows || col >= self.shape.cols {
+            panic!("Indexing outside bounds of array");
+        }
+
+        &self.data[self.to_index(row, col)]
+    }
+}
+
+impl ops::IndexMut<(usize, usize)> for Array2D {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut u8 {
+        if row >= self.shape.rows || col >= self.shape.cols {
+            panic!("Indexing outside bounds of array");
+        }
+
+        let index = self.to_index(row, col);
+        &mut self.data[index]
+    }
+}
+
+impl ops::Add<Array2D> for Array2D {
+    type Output = Array2D;
+
+    /// Adds an `Array2D` to the `Array2D` it is called on element wise.
+    ///
+    /// # Arguments
+    /// `other_array` - An Array2D to be added. Both arrays must have the same dimension.
+    ///
+    /// # Panics!
+    /// Will panic if 2D arrays with different dimensions are added together.
+    ///
+    /// # Examples
+    /// Adding 2D arrays of the same dimension together:
+    ///
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let matrix: Array2D = array2D!([0, 1, 2], [3, 4, 5]);
+    /// let matrix2: Array2D = array2D!([2, 2, 2], [2, 2, 2]);
+    /// let expected_result: Array2D = array2D!([2, 3, 4], [5, 6, 7]);
+    /// assert_eq!(expected_result, matrix + matrix2);
+    /// ```
+    ///
+    /// Adding together 2D arrays with different dimensions:
+    /// ```should_panic
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let matrix: Array2D = array2D!([0, 1, 2], [3, 4, 5]);
+    /// let matrix2: Array2D = array2D!([2, 2, 2, 2], [2, 2, 2, 2]);
+    /// let _ = matrix + matrix2;
+    /// ```
+    fn add(self, other_array: Array2D) -> Array2D {
+        if self.shape != other_array.shape {
+            panic!("Array dimensions must be the same for arrays to be added element wise");
+        }
+
+        let new_array: Vec<u8> = self
+            .data
+            .iter()
+            .zip(other_array.data.iter())
+            .map(|(a, b)| *a + *b)
+            .collect();
+
+        // Struct update syntax - uses values from another instance
+        Array2D {
+            data: new_array,
+            ..self
+        }
+    }
+}
+
+impl ops::Sub<Array2D> for Array2D {
+    type Output = Array2D;
+
+    /// Subtracts an `Array2D` from the `Array2D` it is called on element wise. The elements are
+    /// unsigned, so subtraction saturates at 0 rather than underflowing, i.e. `0 - 1` is `0`.
+    ///
+    /// # Arguments
+    /// `other_array` - An Array2D to be subtracted. Both arrays must have the same dimension.
+    ///
+    /// # Panics!
+    /// Will panic if 2D arrays with different dimensions are subtracted.
+    ///
+    /// # Examples
+    /// Finding the cells covered by a piece:
+    ///
+    /// ```
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let with_piece: Array2D = array2D!([1, 1, 1], [1, 0, 0]);
+    /// let without_piece: Array2D = array2D!([1, 0, 0], [1, 0, 0]);
+    /// let expected_result: Array2D = array2D!([0, 1, 1], [0, 0, 0]);
+    /// assert_eq!(expected_result, with_piece - without_piece);
+    /// ```
+    ///
+    /// Subtracting 2D arrays with different dimensions:
+    /// ```should_panic
+    /// # use dfsolver::{utils::array_2d::*, array2D};
+    /// let matrix: Array2D = array2D!([0, 1, 2], [3, 4, 5]);
+    /// let matrix2: Array2D = array2D!([2, 2, 2, 2], [2, 2, 2, 2]);
+    /// let _ = matrix - matrix2;
+    /// ```
+    fn sub(self, other_array: Array2D) -> Array2D {
+        if self.shape != other_array.shape {
+            panic!("Array dimensions must be the same for arrays to be subtracted element wise");
+        }
+
+        let new_array: Vec<u8> = self
+            .data
+            .iter()
+            .zip(other_array.data.iter())
+            .map(|(a, b)| a.saturating_sub(*b))
+            .collect();
+
+        Array2D {
+            data: new_array,
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for Array2D {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[")?;
+
+        for row_index in 0..self.shape.rows {
+            // For every element except the first, add a comma.
+            // Use the ? operator to return on errors.
+            if row_index != 0 {
+                write!(f, ",\n ")?;
+            }
+
+            write!(f, "[")?;
+
+            for col_index in 0..self.shape.cols {
+                if col_index != 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", self.get(row_index, col_index))?;
+            }
+            write!(f, "]")?;
+        }
+        write!(f, "]")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_value() {
+        // Arrange
+        let matrix: Array2D = array2D!([0, 0, 0], [0, 5, 0], [0, 0, 0]);
+
+        // Act
+        let a = matrix.get(1, 1);
+
+        // Assert
         assert_eq!(5, a);
     }
 
     #[test]
-    #[should_panic]
-    fn get_value_panic() {
+    #[should_panic]
+    fn get_value_panic() {
+        // Arrange
+        let matrix: Array2D = array2D!([0, 0, 0], [0, 0, 0], [0, 0, 0]);
+
+        // Act
+        matrix.get(1, 3);
+    }
+
+    #[test]
+    fn set_value() {
+        // Arrange
+        let mut matrix: Array2D = array2D!([0, 0, 0], [0, 0, 0], [0, 0, 0]);
+
+        // Act
+        matrix.set((1, 1), 5);
+
+        // Assert
+        assert_eq!(5, matrix.get(1, 1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_value_panic() {
+        // Arrange
+        let mut matrix: Array2D = array2D!([0, 0, 0], [0, 0, 0], [0, 0, 0]);
+
+        // Act
+        matrix.set((1, 3), 5);
+    }
+
+    #[test]
+    fn set_checked_in_bounds() {
+        // Arrange
+        let mut matrix: Array2D = array2D!([0, 0, 0], [0, 0, 0], [0, 0, 0]);
+
+        // Act
+        let written = matrix.set_checked((2, 1), 5);
+
+        // Assert
+        assert!(written);
+        assert_eq!(5, matrix.get(2, 1));
+    }
+
+    #[test]
+    fn set_checked_out_of_bounds() {
+        // Arrange
+        let mut matrix: Array2D = array2D!([0, 0, 0], [0, 0, 0], [0, 0, 0]);
+
+        // Act
+        let written = matrix.set_checked((1, 3), 5);
+
+        // Assert
+        assert!(!written);
+        assert_eq!(array2D!([0, 0, 0], [0, 0, 0], [0, 0, 0]), matrix);
+    }
+
+    #[test]
+    fn count_each_value() {
+        // Arrange
+        let matrix: Array2D = array2D!([0, 1, 2], [1, 2, 2], [0, 1, 2]);
+
+        // Act
+        let counts: Vec<usize> = (0..4).map(|value| matrix.count(value)).collect();
+
+        // Assert
+        assert_eq!(vec![2, 3, 4, 0], counts);
+    }
+
+    #[test]
+    fn bitmask_round_trip() {
+        // Arrange
+        let matrix: Array2D = array2D!([1, 0, 1, 1], [0, 0, 1, 0], [1, 1, 0, 1]);
+
+        // Act
+        let mask = matrix.to_bitmask();
+
+        // Assert
+        assert_eq!(0b1011_0100_1101, mask);
+        assert_eq!(matrix, Array2D::from_bitmask(matrix.shape().clone(), mask));
+    }
+
+    #[test]
+    #[should_panic(expected = "A 9 x 8 array does not fit in a 64 bit mask")]
+    fn bitmask_of_large_array() {
+        // Arrange
+        let matrix = Array2D::repeat_row(&[0; 8], 9);
+
+        // Act & Assert
+        matrix.to_bitmask();
+    }
+
+    #[test]
+    fn crop_then_pad_restores_border() {
+        // Arrange
+        let matrix: Array2D = array2D!([0, 0, 0, 0], [0, 1, 2, 0], [0, 3, 4, 0], [0, 0, 0, 0]);
+
+        // Act
+        let cropped = matrix.crop(1, 1, 2, 2);
+
+        // Assert
+        assert_eq!(array2D!([1, 2], [3, 4]), cropped);
+        assert_eq!(matrix, cropped.pad(1, 1, 1, 1, 0));
+    }
+
+    #[test]
+    #[rustfmt::skip::macros(array2D)]
+    fn pad_all_sides() {
+        // Arrange
+        let matrix: Array2D = array2D!([1, 2], [3, 4]);
+        let expected_result: Array2D = array2D!(
+            [9, 9, 9, 9],
+            [9, 1, 2, 9],
+            [9, 3, 4, 9],
+            [9, 9, 9, 9]
+        );
+
+        // Act
+        let padded = matrix.pad(1, 1, 1, 1, 9);
+
+        // Assert
+        assert_eq!(expected_result, padded);
+    }
+
+    #[test]
+    #[rustfmt::skip::macros(array2D)]
+    fn pad_top_and_right() {
+        // Arrange
+        let matrix: Array2D = array2D!([1, 2], [3, 4]);
+        let expected_result: Array2D = array2D!(
+            [0, 0, 0, 0],
+            [1, 2, 0, 0],
+            [3, 4, 0, 0]
+        );
+
+        // Act
+        let padded = matrix.pad(1, 0, 0, 2, 0);
+
+        // Assert
+        assert_eq!(expected_result, padded);
+    }
+
+    #[test]
+    fn flip_horizontally() {
+        // Arrange
+        let mut matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6], [7, 8, 9]);
+        let expected_result: Array2D = array2D!([3, 2, 1], [6, 5, 4], [9, 8, 7]);
+
+        // Act
+        matrix.flip(Axes::Y);
+
+        // Assert
+        assert_eq!(expected_result, matrix);
+    }
+
+    #[test]
+    fn flip_vertically() {
+        // Arrange
+        let mut matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6], [7, 8, 9]);
+        let expected_result: Array2D = array2D!([7, 8, 9], [4, 5, 6], [1, 2, 3]);
+
+        // Act
+        matrix.flip(Axes::X);
+
+        // Assert
+        assert_eq!(expected_result, matrix);
+    }
+
+    #[test]
+    fn flip_vertically_tall_array() {
         // Arrange
-        let matrix: Array2D = array2D!([0, 0, 0], [0, 0, 0], [0, 0, 0]);
+        let mut matrix: Array2D = array2D!([1, 2], [3, 4], [5, 6], [7, 8]);
+        let expected_result: Array2D = array2D!([7, 8], [5, 6], [3, 4], [1, 2]);
 
         // Act
-        matrix.get(1, 3);
+        matrix.flip(Axes::X);
+
+        // Assert
+        assert_eq!(expected_result, matrix);
     }
 
     #[test]
-    fn set_value() {
+    fn flip_vertically_wide_array() {
         // Arrange
-        let mut matrix: Array2D = array2D!([0, 0, 0], [0, 0, 0], [0, 0, 0]);
+        let mut matrix: Array2D = array2D!([1, 2, 3, 4], [5, 6, 7, 8]);
+        let expected_result: Array2D = array2D!([5, 6, 7, 8], [1, 2, 3, 4]);
 
         // Act
-        matrix.set((1, 1), 5);
+        matrix.flip(Axes::X);
 
         // Assert
-        assert_eq!(5, matrix.get(1, 1));
+        assert_eq!(expected_result, matrix);
     }
 
     #[test]
-    #[should_panic]
-    fn set_value_panic() {
+    fn rotate180_wide_array() {
         // Arrange
-        let mut matrix: Array2D = array2D!([0, 0, 0], [0, 0, 0], [0, 0, 0]);
+        let mut matrix: Array2D = array2D!([1, 2, 3, 4], [5, 6, 7, 8]);
+        let expected_result: Array2D = array2D!([8, 7, 6, 5], [4, 3, 2, 1]);
 
         // Act
-        matrix.set((1, 3), 5);
+        matrix.rotate90(2);
+
+        // Assert
+        assert_eq!(expected_result, matrix);
     }
 
     #[test]
-    fn flip_horizontally() {
+    fn get_checked_in_bounds() {
         // Arrange
-        let mut matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6], [7, 8, 9]);
-        let expected_result: Array2D = array2D!([3, 2, 1], [6, 5, 4], [9, 8, 7]);
+        let matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6]);
 
         // Act
-        matrix.flip(Axes::Y);
+        let element = matrix.get_checked(1, 2);
 
         // Assert
-        assert_eq!(expected_result, matrix);
+        assert_eq!(Some(6), element);
     }
 
     #[test]
-    fn flip_vertically() {
+    fn get_checked_row_overflow() {
+        // Arrange
+        let matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6]);
+
+        // Act
+        let element = matrix.get_checked(2, 0);
+
+        // Assert
+        assert_eq!(None, element);
+    }
+
+    #[test]
+    fn get_checked_col_overflow() {
+        // Arrange
+        let matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6]);
+
+        // Act
+        let element = matrix.get_checked(0, 3);
+
+        // Assert
+        assert_eq!(None, element);
+    }
+
+    #[test]
+    fn iter_rows_in_order() {
+        // Arrange
+        let matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6]);
+
+        // Act
+        let rows: Vec<Vec<u8>> = matrix.iter_rows().map(|row| row.to_vec()).collect();
+
+        // Assert
+        assert_eq!(vec![vec![1, 2, 3], vec![4, 5, 6]], rows);
+    }
+
+    #[test]
+    fn iter_cols_in_order() {
+        // Arrange
+        let matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6]);
+
+        // Act
+        let cols: Vec<Vec<u8>> = matrix.iter_cols().collect();
+
+        // Assert
+        assert_eq!(vec![vec![1, 4], vec![2, 5], vec![3, 6]], cols);
+    }
+
+    #[test]
+    fn index_reads_element() {
+        // Arrange
+        let matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6]);
+
+        // Act
+        let element = matrix[(1, 2)];
+
+        // Assert
+        assert_eq!(6, element);
+        assert_eq!(matrix.get(1, 2), element);
+    }
+
+    #[test]
+    fn index_mut_writes_element() {
+        // Arrange
+        let mut matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6]);
+
+        // Act
+        matrix[(0, 0)] = 5;
+
+        // Assert
+        assert_eq!(array2D!([5, 2, 3], [4, 5, 6]), matrix);
+    }
+
+    #[test]
+    #[should_panic(expected = "Indexing outside bounds of array")]
+    fn index_out_of_bounds() {
+        // Arrange
+        let matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6]);
+
+        // Act
+        let _ = matrix[(2, 0)];
+    }
+
+    #[test]
+    fn clone_is_independent_of_original() {
         // Arrange
         let mut matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6], [7, 8, 9]);
-        let expected_result: Array2D = array2D!([7, 8, 9], [4, 5, 6], [1, 2, 3]);
+        let clone = matrix.clone();
 
         // Act
         matrix.flip(Axes::X);
 
         // Assert
-        assert_eq!(expected_result, matrix);
+        assert_eq!(array2D!([1, 2, 3], [4, 5, 6], [7, 8, 9]), clone);
+        assert_ne!(matrix, clone);
     }
 
     #[test]
@@ -539,6 +1458,58 @@ mod tests {
         assert_eq!(expected_result, matrix);
     }
 
+    #[test]
+    fn test_windows_rectangle() {
+        // Arrange
+        let matrix: Array2D = array2D!([0, 1, 2, 3], [4, 5, 6, 7]);
+        let expected_result: Vec<Array2D> = vec![
+            array2D!([0, 1, 2], [4, 5, 6]),
+            array2D!([1, 2, 3], [5, 6, 7]),
+        ];
+
+        // Act
+        let windows: Vec<Array2D> = matrix.windows(2, 3).collect();
+
+        // Assert
+        assert_eq!(expected_result, windows);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_windows_too_large() {
+        // Arrange
+        let matrix: Array2D = array2D!([0, 1], [2, 3]);
+
+        // Act
+        let _ = matrix.windows(3, 1);
+    }
+
+    #[test]
+    fn test_transpose_into() {
+        // Arrange
+        let matrix: Array2D = array2D!([0, 1, 2], [3, 4, 5]);
+        let mut expected_result = matrix.clone();
+        expected_result.transpose();
+        let mut out: Array2D = array2D!([9, 9], [9, 9], [9, 9]);
+
+        // Act
+        matrix.transpose_into(&mut out);
+
+        // Assert
+        assert_eq!(expected_result, out);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_transpose_into_invalid_shape() {
+        // Arrange
+        let matrix: Array2D = array2D!([0, 1, 2], [3, 4, 5]);
+        let mut out: Array2D = array2D!([0, 0, 0], [0, 0, 0]);
+
+        // Act
+        matrix.transpose_into(&mut out);
+    }
+
     #[test]
     fn test_transpose_tranpose() {
         // Arrange
@@ -710,6 +1681,177 @@ mod tests {
         assert_eq!(expected_result, added_matrix);
     }
 
+    #[test]
+    fn test_sub_valid_shapes() {
+        // Arrange
+        let matrix: Array2D = array2D!([2, 3], [4, 5]);
+        let matrix2: Array2D = array2D!([0, 1], [2, 3]);
+        let expected_result: Array2D = array2D!([2, 2], [2, 2]);
+
+        // Act
+        let subtracted_matrix = matrix - matrix2;
+
+        // Assert
+        assert_eq!(expected_result, subtracted_matrix);
+    }
+
+    #[test]
+    fn test_sub_saturates_at_zero() {
+        // Arrange
+        let matrix: Array2D = array2D!([0, 1], [1, 0]);
+        let matrix2: Array2D = array2D!([1, 1], [0, 3]);
+        let expected_result: Array2D = array2D!([0, 0], [1, 0]);
+
+        // Act
+        let subtracted_matrix = matrix - matrix2;
+
+        // Assert
+        assert_eq!(expected_result, subtracted_matrix);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sub_invalid_shapes() {
+        // Arrange
+        let matrix: Array2D = array2D!([0, 1], [2, 3]);
+        let matrix2: Array2D = array2D!([0, 1], [2, 3], [4, 5]);
+
+        // Act & Assert
+        let _ = matrix - matrix2;
+    }
+
+    #[test]
+    fn test_macro_empty() {
+        // Arrange & Act
+        let array: Array2D = array2D!();
+
+        // Assert
+        assert_eq!(Shape { rows: 0, cols: 0 }, array.shape);
+        assert!(array.data.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Indexing outside bounds of array")]
+    fn test_macro_empty_get() {
+        // Arrange
+        let array: Array2D = array2D!();
+
+        // Act
+        array.get(0, 0);
+    }
+
+    #[test]
+    fn test_macro_single_element() {
+        // Arrange & Act
+        let array: Array2D = array2D!([7]);
+
+        // Assert
+        assert_eq!(Shape { rows: 1, cols: 1 }, array.shape);
+        assert_eq!(7, array.get(0, 0));
+    }
+
+    #[test]
+    fn test_from_rows() {
+        // Arrange
+        let rows: Vec<Vec<u8>> = vec![vec![1, 0, 1], vec![0, 1, 0]];
+        let expected_result: Array2D = array2D!([1, 0, 1], [0, 1, 0]);
+
+        // Act
+        let array = Array2D::from_rows(&rows);
+
+        // Assert
+        assert_eq!(Ok(expected_result), array);
+    }
+
+    #[test]
+    fn test_from_rows_ragged() {
+        // Arrange
+        let rows: Vec<Vec<u8>> = vec![vec![1, 0], vec![0, 1], vec![1, 1, 1]];
+
+        // Act
+        let array = Array2D::from_rows(&rows);
+
+        // Assert
+        assert_eq!(
+            Err(DfError::RaggedRow {
+                row: 2,
+                expected_len: 2,
+                found_len: 3
+            }),
+            array
+        );
+    }
+
+    #[test]
+    fn test_from_rows_square() {
+        // Arrange
+        let rows: Vec<Vec<u8>> = vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]];
+        let expected_result: Array2D = array2D!([1, 2, 3], [4, 5, 6], [7, 8, 9]);
+
+        // Act
+        let array = Array2D::from_rows(&rows);
+
+        // Assert
+        assert_eq!(Ok(expected_result), array);
+    }
+
+    #[test]
+    fn test_from_rows_empty() {
+        // Arrange
+        let rows: Vec<Vec<u8>> = Vec::new();
+
+        // Act
+        let array = Array2D::from_rows(&rows);
+
+        // Assert
+        assert_eq!(Err(DfError::NoRows), array);
+    }
+
+    #[test]
+    fn test_repeat_row() {
+        // Arrange
+        let expected_result: Array2D = array2D!([1, 1, 0], [1, 1, 0], [1, 1, 0]);
+
+        // Act
+        let array = Array2D::repeat_row(&[1, 1, 0], 3);
+
+        // Assert
+        assert_eq!(expected_result, array);
+    }
+
+    #[test]
+    fn test_try_add_valid_shapes() {
+        // Arrange
+        let matrix: Array2D = array2D!([0, 1], [2, 3]);
+        let matrix2: Array2D = array2D!([0, 1], [2, 3]);
+        let expected_result: Array2D = array2D!([0, 2], [4, 6]);
+
+        // Act
+        let added_matrix = matrix.try_add(matrix2);
+
+        // Assert
+        assert_eq!(Ok(expected_result), added_matrix);
+    }
+
+    #[test]
+    fn test_try_add_invalid_shapes() {
+        // Arrange
+        let matrix: Array2D = array2D!([0, 1], [2, 3]);
+        let matrix2: Array2D = array2D!([0, 1], [2, 3], [4, 5]);
+
+        // Act
+        let added_matrix = matrix.try_add(matrix2);
+
+        // Assert
+        match added_matrix {
+            Err(DfError::ShapeMismatch { expected, found }) => {
+                assert_eq!(Shape { rows: 2, cols: 2 }, expected);
+                assert_eq!(Shape { rows: 3, cols: 2 }, found);
+            }
+            _ => panic!("Expected a shape mismatch error"),
+        }
+    }
+
     #[test]
     #[should_panic]
     fn test_add_invalid_shapes() {
@@ -782,6 +1924,64 @@ mod tests {
         let matrix2: Array2D = array2D!([0, 1], [2, 3], [4, 5]);
 
         // Act & Assert
-        matrix.append_array(matrix2, Axes::X);
+        matrix.append_array(matrix2, Axes::Y);
+    }
+
+    #[test]
+    fn append_row_below_array() {
+        // Arrange
+        let mut matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6]);
+        let row: Array2D = array2D!([7, 8, 9]);
+
+        // Act
+        matrix.append_array(row, Axes::X);
+
+        // Assert
+        assert_eq!(array2D!([1, 2, 3], [4, 5, 6], [7, 8, 9]), matrix);
+        assert_eq!(&Shape { rows: 3, cols: 3 }, matrix.shape());
+    }
+
+    #[test]
+    fn append_col_right_of_array() {
+        // Arrange
+        let mut matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6]);
+        let col: Array2D = array2D!([7], [8]);
+
+        // Act
+        matrix.append_array(col, Axes::Y);
+
+        // Assert
+        assert_eq!(array2D!([1, 2, 3, 7], [4, 5, 6, 8]), matrix);
+        assert_eq!(&Shape { rows: 2, cols: 4 }, matrix.shape());
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Tried to stack an Array2D with 2 columns below an Array2D with 3 columns."
+    )]
+    fn append_array_mismatched_cols_message() {
+        // Arrange
+        let mut matrix: Array2D = array2D!([1, 2, 3], [4, 5, 6]);
+
+        // Act & Assert
+        matrix.append_array(array2D!([7, 8]), Axes::X);
+    }
+
+    #[test]
+    fn index_round_trip() {
+        // Arrange
+        let matrix = Array2D::repeat_row(&[0; 7], 7);
+
+        for row in 0..7 {
+            for col in 0..7 {
+                // Act
+                let index = matrix.to_index(row, col);
+
+                // Assert
+                assert_eq!(row * 7 + col, index);
+                assert_eq!(index, matrix.shape().to_index(row, col));
+                assert_eq!((row, col), matrix.from_index(index));
+            }
+        }
     }
 }
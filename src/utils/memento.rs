@@ -1,38 +1,54 @@
 use super::array_2d::Array2D;
 
+/// The history of a board's layouts, saved before each piece is placed.
+pub type RecursiveBoardHistory = RecursiveHistory<Array2D>;
+
+/// A saved board layout.
+pub type BoardMemento = Memento<Array2D>;
+
+/// A stack of saved states, restored in the reverse order they were saved.
 #[derive(Clone)]
-pub struct RecursiveBoardHistory {
-    history: Vec<Box<BoardMemento>>,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(clippy::vec_box)]
+pub struct RecursiveHistory<T> {
+    history: Vec<Box<Memento<T>>>,
 }
 
-impl RecursiveBoardHistory {
-    pub fn new() -> RecursiveBoardHistory {
-        RecursiveBoardHistory {
+impl<T> RecursiveHistory<T> {
+    pub fn new() -> RecursiveHistory<T> {
+        RecursiveHistory {
             history: Vec::new(),
         }
     }
 
-    pub fn add_memento(&mut self, memento: Box<BoardMemento>) {
+    pub fn add_memento(&mut self, memento: Box<Memento<T>>) {
         self.history.push(memento);
     }
 
-    pub fn get_memento(&mut self) -> Box<BoardMemento> {
+    pub fn get_memento(&mut self) -> Box<Memento<T>> {
         self.history.pop().unwrap()
     }
 }
 
+impl<T> Default for RecursiveHistory<T> {
+    fn default() -> RecursiveHistory<T> {
+        RecursiveHistory::new()
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
-pub struct BoardMemento {
-    backup: Array2D,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Memento<T> {
+    backup: T,
 }
 
-impl BoardMemento {
-    /// Creates a `Memento` of the current state of the `BoardModel` it is called on.
-    pub fn new(backup: Array2D) -> BoardMemento {
-        BoardMemento { backup }
+impl<T> Memento<T> {
+    /// Creates a `Memento` of the state passed in, e.g. the layout of a `BoardModel`.
+    pub fn new(backup: T) -> Memento<T> {
+        Memento { backup }
     }
 
-    pub fn get_state(self) -> Array2D {
+    pub fn get_state(self) -> T {
         self.backup
     }
 }
@@ -72,7 +88,7 @@ mod tests {
         recursive_history.add_memento(memento);
 
         // Assert
-        assert_eq!(expected_result, *recursive_history.history.get(0).unwrap());
+        assert_eq!(expected_result, *recursive_history.history.first().unwrap());
         assert_eq!(1, recursive_history.history.len());
     }
 
@@ -95,4 +111,25 @@ mod tests {
         assert_eq!(expected_result, backup);
         assert_eq!(0, recursive_history.history.len())
     }
+
+    #[test]
+    fn memento_of_compound_state() {
+        // Arrange
+        let board_layout = array2D!([1, 0], [0, 1]);
+        let used_flags = vec![true, false, true];
+        let mut recursive_history: RecursiveHistory<(Array2D, Vec<bool>)> = RecursiveHistory::new();
+
+        // Act
+        recursive_history.add_memento(Box::new(Memento::new((
+            board_layout.clone(),
+            used_flags.clone(),
+        ))));
+        recursive_history.add_memento(Box::new(Memento::new((array2D!([1]), vec![]))));
+        recursive_history.get_memento();
+        let state = recursive_history.get_memento().get_state();
+
+        // Assert
+        assert_eq!((board_layout, used_flags), state);
+        assert!(recursive_history.history.is_empty());
+    }
 }
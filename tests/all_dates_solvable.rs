@@ -0,0 +1,8 @@
+use dfsolver::puzzle::solver;
+
+#[test]
+#[ignore]
+/// Solves every date of the year, stopping at the first solution of each
+fn all_dates_solvable() {
+    solver::assert_all_dates_solvable();
+}
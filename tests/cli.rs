@@ -0,0 +1,119 @@
+use std::process::Command;
+
+#[test]
+/// Prints the first solution of 1 January as a grid
+fn first_solution_as_grid() {
+    let output = Command::new(env!("CARGO_BIN_EXE_dfsolver"))
+        .args(["--day", "1", "--month", "1", "--first", "--format", "grid"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(7, lines.len());
+    assert!(lines.iter().all(|line| line.chars().count() == 7));
+    assert!(!stdout.contains('.'));
+}
+
+#[test]
+/// Rejects a date which does not exist
+fn invalid_date_fails() {
+    let output = Command::new(env!("CARGO_BIN_EXE_dfsolver"))
+        .args(["--day", "31", "--month", "4"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert_eq!(
+        "Day 31 of month 4 is not a valid date\n",
+        String::from_utf8(output.stderr).unwrap()
+    );
+}
+
+#[test]
+/// Prints the first solution as an SVG document
+fn first_solution_as_svg() {
+    let output = Command::new(env!("CARGO_BIN_EXE_dfsolver"))
+        .args(["--day", "6", "--month", "10", "--first", "--format", "svg"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.starts_with("<svg"));
+    assert!(stdout.trim_end().ends_with("</svg>"));
+}
+
+#[test]
+/// Fails cleanly when the weekday board has no solution to draw as SVG
+fn weekday_board_as_svg() {
+    let output = Command::new(env!("CARGO_BIN_EXE_dfsolver"))
+        .args([
+            "--day",
+            "6",
+            "--month",
+            "10",
+            "--weekday",
+            "tue",
+            "--first",
+            "--format",
+            "svg",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert_eq!(
+        "No solution was found\n",
+        String::from_utf8(output.stderr).unwrap()
+    );
+}
+
+#[test]
+/// Rejects a weekday on the board without the days of the week
+fn weekday_on_dragon_fjord_board_fails() {
+    let output = Command::new(env!("CARGO_BIN_EXE_dfsolver"))
+        .args([
+            "--day",
+            "6",
+            "--month",
+            "10",
+            "--variant",
+            "dragon-fjord",
+            "--weekday",
+            "tue",
+        ])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert_eq!(
+        "The dragon-fjord board has no days of the week to leave a weekday exposed\n",
+        String::from_utf8(output.stderr).unwrap()
+    );
+}
+
+#[test]
+/// Prints every solution as JSON which parses back into arrays of 8 pieces
+fn solutions_as_json() {
+    let output = Command::new(env!("CARGO_BIN_EXE_dfsolver"))
+        .args(["--day", "6", "--month", "10", "--format", "json"])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let solutions: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let solutions = solutions.as_array().unwrap();
+    assert!(!solutions.is_empty());
+    for solution in solutions {
+        let pieces = solution.as_array().unwrap();
+        assert_eq!(8, pieces.len());
+        for piece in pieces {
+            assert!(piece["name"].is_string());
+            assert!(piece["row"].is_u64());
+            assert!(piece["col"].is_u64());
+            assert!(piece["orientation"].is_array());
+        }
+    }
+}
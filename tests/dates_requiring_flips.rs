@@ -0,0 +1,22 @@
+use dfsolver::puzzle::{board, solver, solver::SolverSingleThreaded};
+
+#[test]
+#[ignore]
+/// Checks every date listed as requiring flips against a full search with and without flips
+fn dates_requiring_flips() {
+    let valid_dates = board::valid_dates();
+    let dates = solver::dates_requiring_flips();
+
+    assert!(!dates.is_empty());
+    for (day, month) in dates {
+        assert!(valid_dates.contains(&(day, month)));
+
+        let mut no_flips = SolverSingleThreaded::new(day, month).without_flips();
+        no_flips.find_solution_set();
+        assert!(no_flips.get_solution_set().is_empty());
+
+        let mut full = SolverSingleThreaded::new(day, month);
+        full.find_solution_set();
+        assert!(!full.get_solution_set().is_empty());
+    }
+}
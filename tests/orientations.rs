@@ -0,0 +1,64 @@
+use dfsolver::puzzle::piece;
+use dfsolver::utils::array_2d::Array2D;
+
+/// Returns the array rotated 90 degrees anti-clockwise, built cell by cell rather than with the
+/// `Array2D` operations used by the pieces.
+fn rotate_anticlockwise(array: &Array2D) -> Array2D {
+    let (rows, cols) = (array.shape().rows, array.shape().cols);
+    let rotated: Vec<Vec<u8>> = (0..cols)
+        .map(|row| {
+            (0..rows)
+                .map(|col| array.get(col, cols - 1 - row))
+                .collect()
+        })
+        .collect();
+
+    Array2D::from_rows(&rotated).unwrap()
+}
+
+/// Returns the array mirrored left to right, built cell by cell.
+fn mirror(array: &Array2D) -> Array2D {
+    let (rows, cols) = (array.shape().rows, array.shape().cols);
+    let mirrored: Vec<Vec<u8>> = (0..rows)
+        .map(|row| {
+            (0..cols)
+                .map(|col| array.get(row, cols - 1 - col))
+                .collect()
+        })
+        .collect();
+
+    Array2D::from_rows(&mirrored).unwrap()
+}
+
+#[test]
+/// The orientations reached by the piece state machine are exactly the distinct rotations and
+/// reflections of every standard piece
+fn state_machine_matches_symmetries() {
+    for mut piece in piece::create_piece_models() {
+        // Orientations reached by the state machine, in order
+        piece.reset();
+        let mut reached: Vec<Array2D> = Vec::new();
+        while !piece.is_exhausted() {
+            if !reached.contains(piece.current_orientation()) {
+                reached.push(piece.current_orientation().clone());
+            }
+            piece.next_unique_orientation();
+        }
+
+        // Every rotation of the initial orientation and its mirror image
+        let initial = piece.unique_orientations().swap_remove(0);
+        let mut symmetries: Vec<Array2D> = Vec::new();
+        for mut orientation in [initial.clone(), mirror(&initial)] {
+            for _ in 0..4 {
+                symmetries.push(orientation.clone());
+                orientation = rotate_anticlockwise(&orientation);
+            }
+        }
+        symmetries.sort();
+        symmetries.dedup();
+
+        assert_eq!(piece.unique_orientations(), reached, "{}", piece.get_name());
+        reached.sort();
+        assert_eq!(symmetries, reached, "{}", piece.get_name());
+    }
+}
@@ -0,0 +1,16 @@
+use dfsolver::puzzle::{
+    board,
+    solver::{self, SolveMode},
+};
+
+#[test]
+#[ignore]
+/// Times the first solution of every date of the year
+fn rank_dates_by_solve_time() {
+    let ranking = solver::rank_dates_by_solve_time(SolveMode::First);
+
+    let mut dates: Vec<(usize, usize)> = ranking.iter().map(|(date, _)| *date).collect();
+    dates.sort_by_key(|&(day, month)| (month, day));
+    assert_eq!(board::valid_dates(), dates);
+    assert!(ranking.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+}
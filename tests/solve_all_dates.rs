@@ -0,0 +1,14 @@
+use dfsolver::puzzle::{board, solver};
+
+#[test]
+#[ignore]
+/// Counts the unique solutions of every date of the year
+fn solve_all_dates() {
+    let counts = solver::solve_all_dates();
+
+    assert_eq!(366, counts.len());
+    let mut dates: Vec<(usize, usize)> = counts.keys().copied().collect();
+    dates.sort_by_key(|&(day, month)| (month, day));
+    assert_eq!(board::valid_dates(), dates);
+    assert!(counts.values().all(|&count| count > 0));
+}